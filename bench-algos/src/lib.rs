@@ -0,0 +1,169 @@
+//! The benchmark programs, without any zkVM I/O.
+//!
+//! All arithmetic wraps at 64 bits.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::max;
+
+/// The `n`th Fibonacci number, computed with a loop.
+pub fn fib(n: u64) -> u64 {
+    let mut a: u64 = 0;
+    let mut b: u64 = 1;
+    for _ in 0..n {
+        let c: u64 = a.wrapping_add(b);
+        a = b;
+        b = c;
+    }
+    a
+}
+
+// [0, 1, 2, 3] = |0 1|
+//                |2 3|
+type Matrix2x2 = [u64; 4];
+
+fn matmul(a: Matrix2x2, b: Matrix2x2) -> Matrix2x2 {
+    [
+        a[0].wrapping_mul(b[0])
+            .wrapping_add(a[1].wrapping_mul(b[2])),
+        a[0].wrapping_mul(b[1])
+            .wrapping_add(a[1].wrapping_mul(b[3])),
+        a[2].wrapping_mul(b[0])
+            .wrapping_add(a[3].wrapping_mul(b[2])),
+        a[2].wrapping_mul(b[1])
+            .wrapping_add(a[3].wrapping_mul(b[3])),
+    ]
+}
+
+fn fast_matexp(mut b: Matrix2x2, mut e: u64) -> Matrix2x2 {
+    let mut acc = [1, 0, 0, 1]; // identity matrix
+
+    while e > 0 {
+        if e % 2 == 1 {
+            // odd?
+            acc = matmul(b, acc);
+            e = (e - 1) / 2;
+        } else {
+            e /= 2;
+        }
+        b = matmul(b, b);
+    }
+    acc
+}
+
+/// The `n`th Fibonacci number, computed by fast matrix exponentiation:
+///
+/// ```text
+/// |0 1|^n = |F(n-1) F(n)  |
+/// |1 1|     |F(n)   F(n+1)|
+/// ```
+pub fn fastfib(n: u64) -> u64 {
+    fast_matexp([0, 1, 1, 1], n)[1]
+}
+
+pub fn sum(nums: &[u64]) -> u64 {
+    nums.iter().fold(0, |acc, &n| acc.wrapping_add(n))
+}
+
+/// A longest common subsequence, from a table of lengths.
+pub fn lcs(xs: &str, ys: &str) -> String {
+    let xs: Vec<char> = xs.chars().collect();
+    let ys: Vec<char> = ys.chars().collect();
+    let (m, n) = (xs.len(), ys.len());
+
+    let mut tab: Vec<VecDeque<i32>> = vec![VecDeque::from(vec![0; n + 1]); m + 1];
+
+    for (i, &x) in xs.iter().enumerate() {
+        let mut row = VecDeque::from(vec![0]);
+        for (j, &y) in ys.iter().enumerate() {
+            let val = if x == y {
+                1 + tab[i][j]
+            } else {
+                max(tab[i][j + 1], row[j])
+            };
+            row.push_back(val);
+        }
+        tab[i + 1] = row;
+    }
+
+    construct(&xs, &ys, &tab)
+}
+
+fn construct(xs: &[char], ys: &[char], tab: &[VecDeque<i32>]) -> String {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (xs.len(), ys.len());
+
+    while i > 0 && j > 0 {
+        if xs[i - 1] == ys[j - 1] {
+            result.push(xs[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if tab[i - 1][j] > tab[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    result.reverse();
+    result.into_iter().collect()
+}
+
+/// A longest common subsequence, from a table of the subsequences themselves.
+pub fn lcs2(s1: &str, s2: &str) -> String {
+    let s1arr: Vec<char> = s1.chars().collect();
+    let s2arr: Vec<char> = s2.chars().collect();
+    let (s1len, s2len) = (s1arr.len(), s2arr.len());
+
+    let lcs_matrix = calculate_lcs2(&s1arr, &s2arr);
+
+    lcs_matrix[s1len][s2len].clone()
+}
+
+fn calculate_lcs2(s1: &[char], s2: &[char]) -> Vec<Vec<String>> {
+    let (s1len, s2len) = (s1.len(), s2.len());
+    let mut lcs_matrix: Vec<Vec<String>> = vec![vec![String::new(); s2len + 1]; s1len + 1];
+
+    for i in 1..=s1len {
+        for j in 1..=s2len {
+            if s1[i - 1] == s2[j - 1] {
+                lcs_matrix[i][j] = format!("{}{}", lcs_matrix[i - 1][j - 1], s1[i - 1]);
+            } else {
+                let l1 = &lcs_matrix[i][j - 1];
+                let l2 = &lcs_matrix[i - 1][j];
+                // Lengths in chars, not bytes, for non-ASCII inputs.
+                lcs_matrix[i][j] = if l1.chars().count() > l2.chars().count() {
+                    l1.clone()
+                } else {
+                    l2.clone()
+                };
+            }
+        }
+    }
+
+    lcs_matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fastfib_matches_fib() {
+        for n in 0..100 {
+            assert_eq!(fastfib(n), fib(n), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn fastfib_at_u64_max() {
+        // F(2^64 - 1) mod 2^64
+        assert_eq!(fastfib(u64::MAX), 800812746651928290);
+    }
+}
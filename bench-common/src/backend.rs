@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::input::{env_or, Input, Program};
+use crate::measure::{Measurement, PhaseMeter};
+use crate::reference;
+use crate::report::{Backend, Report};
+
+/// The value a benchmark program commits: a number (fib, fastfib, sum) or a
+/// string (lcs, lcs2).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Output {
+    N(u64),
+    Str(String),
+}
+
+impl From<u64> for Output {
+    fn from(n: u64) -> Self {
+        Output::N(n)
+    }
+}
+
+impl From<String> for Output {
+    fn from(s: String) -> Self {
+        Output::Str(s)
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::N(n) => write!(f, "{n}"),
+            Output::Str(s) => write!(f, "{s:?}"),
+        }
+    }
+}
+
+/// Serialized sizes, in bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofSizes {
+    pub proof_bytes: usize,
+    /// `None` for backends whose verifier is not given a separate key.
+    pub vk_bytes: Option<usize>,
+    /// The proof each stage of `prove` produced, for backends that prove in
+    /// several stages, each reducing the proof of the one before.
+    pub stages: Vec<(&'static str, usize)>,
+}
+
+/// One zkVM running one benchmark program, split into the phases [`run`]
+/// times.
+///
+/// Implementations live next to their backend (`sp1/backend`,
+/// `risc0/backend`, ...) and are responsible for encoding the [`Input`] the
+/// way their guest expects and for decoding the guest's committed output.
+pub trait ZkvmBackend {
+    const BACKEND: Backend;
+
+    /// Keys, prover handles or parsed programs produced by `setup`.
+    type Setup;
+    type Proof;
+
+    /// The benchmark program being run, or `None` for an ad hoc program,
+    /// which gets no reference check.
+    fn program(&self) -> Option<Program>;
+
+    /// What is being run, for logs and benchmark names.
+    fn name(&self) -> String {
+        self.program()
+            .map_or("adhoc".to_string(), |p| p.to_string())
+    }
+
+    fn setup(&mut self, input: &Input) -> Self::Setup;
+
+    /// Runs the guest without proving.
+    fn execute(&mut self, setup: &mut Self::Setup, input: &Input) -> Output;
+
+    fn prove(&mut self, setup: &mut Self::Setup, input: &Input) -> (Self::Proof, Output);
+
+    /// Panics if the proof does not verify.
+    fn verify(&mut self, setup: &Self::Setup, proof: Self::Proof);
+
+    /// Serialized sizes of the proof and of what a verifier needs besides it.
+    fn proof_sizes(&self, setup: &Self::Setup, proof: &Self::Proof) -> ProofSizes;
+
+    /// Measurements of parts of the phase that just ran, to be reported as
+    /// `<phase>.<stage>` next to the phase itself. Backends that time parts of
+    /// a phase collect them with [`PhaseMeter`] and hand them over here.
+    fn take_stages(&mut self) -> Vec<(&'static str, Measurement)> {
+        Vec::new()
+    }
+
+    /// Fills in the backend-specific parts of the report (prover options,
+    /// work counters, ...) once all phases have run.
+    fn annotate(&self, _report: &mut Report) {}
+}
+
+/// How far [`run`] goes through the phases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    /// Setup, execute, prove and verify.
+    #[default]
+    Full,
+    /// Setup and execute only, for checking that an input fits and what it
+    /// costs without waiting for a proof.
+    Execute,
+}
+
+impl Mode {
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::Full => "full",
+            Mode::Execute => "execute",
+        }
+    }
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Mode::Full, Mode::Execute]
+            .into_iter()
+            .find(|m| m.name() == s)
+            .ok_or_else(|| format!("unknown mode {s:?}"))
+    }
+}
+
+/// How many times, and how far, [`run`] goes through the phases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunConfig {
+    /// Measured iterations.
+    pub repetitions: usize,
+    /// Iterations run before the measured ones and left out of the report.
+    pub warmup: usize,
+    pub mode: Mode,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            repetitions: 1,
+            warmup: 0,
+            mode: Mode::Full,
+        }
+    }
+}
+
+impl RunConfig {
+    /// Reads `BENCH_REPETITIONS`, `BENCH_WARMUP` and `BENCH_MODE`.
+    pub fn from_env() -> Self {
+        let def = Self::default();
+        Self {
+            repetitions: env_or("BENCH_REPETITIONS", def.repetitions),
+            warmup: env_or("BENCH_WARMUP", def.warmup),
+            mode: env_or("BENCH_MODE", def.mode),
+        }
+    }
+
+    /// The environment a harness needs to read this config back through
+    /// [`RunConfig::from_env`].
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("BENCH_REPETITIONS", self.repetitions.to_string()),
+            ("BENCH_WARMUP", self.warmup.to_string()),
+            ("BENCH_MODE", self.mode.to_string()),
+        ]
+    }
+}
+
+/// Adds the measurement of `phase` and of the stages the backend timed within
+/// it to `report`.
+fn record<B: ZkvmBackend>(backend: &mut B, report: &mut Report, phase: &str, mut m: Measurement) {
+    for (stage, s) in backend.take_stages() {
+        // The stage's meter reset the peak the phase's meter was tracking.
+        m.peak_rss_bytes = m.peak_rss_bytes.max(s.peak_rss_bytes);
+        report.phase(&format!("{phase}.{stage}"), s);
+    }
+    report.phase(phase, m);
+}
+
+/// Goes through the phases of `mode` once, adding one sample per phase to
+/// `report`.
+fn iterate<B: ZkvmBackend>(
+    backend: &mut B,
+    input: &Input,
+    mode: Mode,
+    report: &mut Report,
+) -> Output {
+    // setup
+    let it = PhaseMeter::start();
+    let mut setup = backend.setup(input);
+    record(backend, report, "setup", it.stop());
+
+    // execution
+    let it = PhaseMeter::start();
+    let output = backend.execute(&mut setup, input);
+    record(backend, report, "execute", it.stop());
+    if mode == Mode::Execute {
+        return output;
+    }
+
+    // proof
+    let it = PhaseMeter::start();
+    let (proof, proved) = backend.prove(&mut setup, input);
+    record(backend, report, "prove", it.stop());
+    assert_eq!(output, proved, "proven output differs from execution");
+    let sizes = backend.proof_sizes(&setup, &proof);
+    report.proof_bytes = Some(sizes.proof_bytes);
+    report.vk_bytes = sizes.vk_bytes;
+    for (stage, bytes) in sizes.stages {
+        report.stage_proof_bytes.insert(stage.to_string(), bytes);
+    }
+
+    // verify
+    let it = PhaseMeter::start();
+    backend.verify(&setup, proof);
+    record(backend, report, "verify", it.stop());
+
+    output
+}
+
+/// [`run_with`] configured from the environment.
+pub fn run<B: ZkvmBackend>(backend: B, input: Input) -> Report {
+    run_with(backend, input, &RunConfig::from_env())
+}
+
+/// Runs the phases of `config.mode` of `backend` on `input`
+/// `config.repetitions` times, after `config.warmup` unmeasured iterations,
+/// and returns the measurements.
+pub fn run_with<B: ZkvmBackend>(mut backend: B, input: Input, config: &RunConfig) -> Report {
+    assert!(config.repetitions > 0, "at least one repetition is needed");
+    let mut report = Report::new(backend.program(), B::BACKEND, input.clone());
+    report.repetitions = config.repetitions;
+    report.warmup = config.warmup;
+    report.mode = config.mode;
+
+    for _ in 0..config.warmup {
+        let mut discarded = report.clone();
+        iterate(&mut backend, &input, config.mode, &mut discarded);
+    }
+
+    let mut output = None;
+    for _ in 0..config.repetitions {
+        let out = iterate(&mut backend, &input, config.mode, &mut report);
+        if let Some(prev) = &output {
+            assert_eq!(prev, &out, "output changed between repetitions");
+        }
+        output = Some(out);
+    }
+    let output = output.unwrap();
+    eprintln!("{}({input}) = {output}", backend.name());
+
+    if let Some(program) = backend.program() {
+        let check = reference::check(B::BACKEND, program, &input, &output);
+        if !check.passed {
+            eprintln!("wrong output, expected {}", check.expected);
+        }
+        report.check = Some(check);
+    }
+    report.output = Some(output);
+    backend.annotate(&mut report);
+    report
+}
@@ -0,0 +1,99 @@
+//! Criterion groups over the phases of a [`ZkvmBackend`], for the `benches/`
+//! targets of the harnesses.
+
+pub use criterion;
+
+use criterion::{BatchSize, Criterion};
+use std::time::Duration;
+
+use crate::backend::ZkvmBackend;
+use crate::input::Input;
+
+/// Proving takes seconds, so fewer and longer samples than criterion's
+/// default.
+pub fn config() -> Criterion {
+    Criterion::default()
+        .measurement_time(Duration::from_secs(15))
+        .sample_size(10)
+}
+
+/// Benchmarks `execute`, `prove` and `verify` of `backend` on `input` in the
+/// group `{name}-{backend}`. Setup runs once, outside the measurements.
+///
+/// Verification consumes the proof, so every verify iteration gets a fresh
+/// one from `copy_proof`. Stages the backend times on its own are dropped
+/// after every call, as criterion measures the phases as a whole.
+pub fn phases<B: ZkvmBackend>(
+    c: &mut Criterion,
+    mut backend: B,
+    input: &Input,
+    copy_proof: fn(&B::Proof) -> B::Proof,
+) {
+    let mut group = c.benchmark_group(format!("{}-{}", backend.name(), B::BACKEND));
+    let mut setup = backend.setup(input);
+    backend.take_stages();
+
+    group.bench_function("execute", |b| {
+        b.iter(|| {
+            let output = backend.execute(&mut setup, input);
+            backend.take_stages();
+            output
+        })
+    });
+    group.bench_function("prove", |b| {
+        b.iter(|| {
+            let proof = backend.prove(&mut setup, input);
+            backend.take_stages();
+            proof
+        })
+    });
+
+    let (proof, _) = backend.prove(&mut setup, input);
+    backend.take_stages();
+    group.bench_function("verify", |b| {
+        b.iter_batched(
+            || copy_proof(&proof),
+            |proof| {
+                backend.verify(&setup, proof);
+                backend.take_stages();
+            },
+            BatchSize::PerIteration,
+        )
+    });
+
+    group.finish();
+}
+
+/// [`phases`] of a benchmark program's backend on the program's input from
+/// the environment.
+pub fn program_phases<B: ZkvmBackend>(
+    c: &mut Criterion,
+    backend: B,
+    copy_proof: fn(&B::Proof) -> B::Proof,
+) {
+    let program = backend.program().expect("not a benchmark program");
+    phases(c, backend, &program.input_from_env(), copy_proof);
+}
+
+/// The whole `benches/` target of a harness: [`program_phases`] of `$backend`
+/// as the criterion `main`.
+///
+/// ```ignore
+/// bench_common::criterion_phases!(Sp1::new(Program::Fib, ELF), Clone::clone);
+/// ```
+#[macro_export]
+macro_rules! criterion_phases {
+    ($backend:expr, $copy_proof:expr) => {
+        fn phases(c: &mut $crate::bench::criterion::Criterion) {
+            $crate::bench::program_phases(c, $backend, $copy_proof);
+        }
+
+        $crate::bench::criterion::criterion_group! {
+            name = benches;
+            config = $crate::bench::config();
+            targets = phases,
+        }
+
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
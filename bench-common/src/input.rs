@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Debug, Display};
+use std::str::FromStr;
+
+pub const DEFAULT_FIB_ARG: u64 = 100000;
+pub const DEFAULT_FASTFIB_ARG: u64 = u64::MAX - 1;
+pub const DEFAULT_SUM_ARG: u64 = 100000;
+pub const DEFAULT_LCS_ARG1: &str = "When in the Course of human events, it becomes necessary for one people to dissolve the political bands which have connected them with another";
+pub const DEFAULT_LCS_ARG2: &str = "There must be some kind of way outta here Said the joker to the thief. There's too much confusion. I can't get no relief.";
+
+pub fn env_or<T: FromStr>(var: &str, def: T) -> T
+where
+    <T as FromStr>::Err: Debug,
+{
+    std::env::var(var)
+        .map(|s| {
+            s.parse::<T>()
+                .unwrap_or_else(|_| panic!("Could not parse {}", var))
+        })
+        .unwrap_or(def)
+}
+
+/// The benchmark programs every backend implements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Program {
+    Fib,
+    FastFib,
+    Sum,
+    Lcs,
+    Lcs2,
+}
+
+impl Program {
+    pub const ALL: [Program; 5] = [
+        Program::Fib,
+        Program::FastFib,
+        Program::Sum,
+        Program::Lcs,
+        Program::Lcs2,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Program::Fib => "fib",
+            Program::FastFib => "fastfib",
+            Program::Sum => "sum",
+            Program::Lcs => "lcs",
+            Program::Lcs2 => "lcs2",
+        }
+    }
+
+    /// Environment variables the program's input is read from.
+    pub fn env_vars(self) -> &'static [&'static str] {
+        match self {
+            Program::Fib => &["FIB_ARG"],
+            Program::FastFib => &["FASTFIB_ARG"],
+            Program::Sum => &["SUM_ARG"],
+            Program::Lcs => &["LCS_ARG1", "LCS_ARG2"],
+            Program::Lcs2 => &["LCS2_ARG1", "LCS2_ARG2"],
+        }
+    }
+
+    pub fn default_input(self) -> Input {
+        match self {
+            Program::Fib => Input::N(DEFAULT_FIB_ARG),
+            Program::FastFib => Input::N(DEFAULT_FASTFIB_ARG),
+            Program::Sum => Input::N(DEFAULT_SUM_ARG),
+            Program::Lcs | Program::Lcs2 => {
+                Input::Args(DEFAULT_LCS_ARG1.into(), DEFAULT_LCS_ARG2.into())
+            }
+        }
+    }
+
+    /// Reads the program's input from its environment variables, falling back
+    /// to [`Program::default_input`] for any that are unset.
+    pub fn input_from_env(self) -> Input {
+        self.input_from_env_or(self.default_input())
+    }
+
+    /// Like [`Program::input_from_env`], falling back to `default` instead.
+    pub fn input_from_env_or(self, default: Input) -> Input {
+        let vars = self.env_vars();
+        match default {
+            Input::N(n) => Input::N(env_or(vars[0], n)),
+            Input::Args(a, b) => Input::Args(env_or(vars[0], a), env_or(vars[1], b)),
+            Input::Values(_) => unreachable!("benchmark programs take numbers or pairs"),
+        }
+    }
+
+    /// The environment a harness needs to read `input` back through
+    /// [`Program::input_from_env`].
+    pub fn input_env(self, input: &Input) -> Vec<(&'static str, String)> {
+        let vars = self.env_vars();
+        match input {
+            Input::N(n) => vec![(vars[0], n.to_string())],
+            Input::Args(a, b) => vec![(vars[0], a.clone()), (vars[1], b.clone())],
+            Input::Values(_) => panic!("{self} takes no {input:?}"),
+        }
+    }
+
+    /// Whether the program takes a number (as opposed to a pair of strings).
+    pub fn takes_n(self) -> bool {
+        matches!(self.default_input(), Input::N(_))
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Program {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Program::ALL
+            .into_iter()
+            .find(|p| p.name() == s)
+            .ok_or_else(|| format!("unknown program {s:?}"))
+    }
+}
+
+/// A program input: a single number (fib, fastfib, sum) or a pair of
+/// strings (lcs, lcs2). Ad hoc programs take a list of arguments, as given on
+/// the command line.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Input {
+    N(u64),
+    Args(String, String),
+    Values(Vec<String>),
+}
+
+impl Input {
+    pub fn expect_n(&self) -> u64 {
+        match self {
+            Input::N(n) => *n,
+            _ => panic!("expected a numeric input, got {self:?}"),
+        }
+    }
+
+    pub fn expect_args(&self) -> (String, String) {
+        match self {
+            Input::Args(a, b) => (a.clone(), b.clone()),
+            _ => panic!("expected a pair of strings, got {self:?}"),
+        }
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::N(n) => write!(f, "{n}"),
+            Input::Args(a, b) => write!(f, "{a:?}, {b:?}"),
+            Input::Values(vs) => write!(f, "{}", vs.join(", ")),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Input parsing, phase measurements, reference outputs and the report type
+//! shared by every harness.
+
+pub mod backend;
+#[cfg(feature = "criterion")]
+pub mod bench;
+pub mod input;
+pub mod measure;
+pub mod reference;
+pub mod report;
+pub mod stats;
+
+pub use backend::{run, run_with, Mode, Output, ProofSizes, RunConfig, ZkvmBackend};
+pub use input::{env_or, Input, Program};
+pub use measure::{Measurement, PhaseMeter};
+pub use reference::Check;
+pub use report::{Backend, CoreOpts, Metrics, Phase, Report};
+pub use stats::Summary;
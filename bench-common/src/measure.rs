@@ -0,0 +1,55 @@
+use std::time::Instant;
+
+/// Wall-clock time and peak resident memory of one run of a phase.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    pub secs: f64,
+    /// Peak resident set size while the phase ran, including whatever the
+    /// process already held when it started. `None` where the peak cannot be
+    /// reset, i.e. outside Linux.
+    pub peak_rss_bytes: Option<u64>,
+}
+
+/// Measures a single benchmark phase.
+pub struct PhaseMeter {
+    start: Instant,
+    rss_reset: bool,
+}
+
+impl PhaseMeter {
+    pub fn start() -> Self {
+        let rss_reset = reset_peak_rss();
+        Self {
+            start: Instant::now(),
+            rss_reset,
+        }
+    }
+
+    pub fn stop(self) -> Measurement {
+        let secs = self.start.elapsed().as_secs_f64();
+        Measurement {
+            secs,
+            peak_rss_bytes: self.rss_reset.then(peak_rss_bytes).flatten(),
+        }
+    }
+}
+
+/// Resets the kernel's high-water mark of the process' resident memory to
+/// its current value (Linux >= 4.0). Returns false if that is not possible.
+fn reset_peak_rss() -> bool {
+    std::fs::write("/proc/self/clear_refs", "5").is_ok()
+}
+
+/// `VmHWM` from `/proc/self/status`.
+fn peak_rss_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kb: u64 = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
@@ -0,0 +1,224 @@
+//! Native implementations of the benchmark programs, written independently of
+//! the guests so that a guest bug does not show up in both.
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend::Output;
+use crate::input::{Input, Program};
+use crate::report::Backend;
+
+/// Lurk numbers are elements of the BabyBear field, so Lurk reads numeric
+/// inputs and computes numeric outputs modulo its prime.
+const BABY_BEAR: u128 = 2013265921;
+
+/// Whether a backend's output agrees with the native reference.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Check {
+    pub passed: bool,
+    /// The reference output, or a description of what any valid output must
+    /// satisfy where several are correct (lcs, lcs2).
+    pub expected: String,
+}
+
+/// The arithmetic every backend but Lurk uses: `u64` wrapping on overflow.
+fn modulus(backend: Backend) -> u128 {
+    match backend {
+        Backend::Lurk => BABY_BEAR,
+        _ => 1 << 64,
+    }
+}
+
+/// `(F(n), F(n + 1))` modulo `m`, by fast doubling rather than the loop or
+/// the matrix power the guests use.
+fn fib_pair(n: u64, m: u128) -> (u128, u128) {
+    if n == 0 {
+        return (0, 1 % m);
+    }
+    let (a, b) = fib_pair(n / 2, m);
+    // F(2k) = F(k) (2 F(k+1) - F(k)), F(2k+1) = F(k)^2 + F(k+1)^2
+    let even = a * ((2 * b + m - a) % m) % m;
+    let odd = (a * a % m + b * b % m) % m;
+    if n & 1 == 0 {
+        (even, odd)
+    } else {
+        (odd, (even + odd) % m)
+    }
+}
+
+/// The `n`th Fibonacci number modulo `m`, with `F(0) = 0`.
+pub fn fib(n: u64, m: u128) -> u64 {
+    fib_pair(n, m).0 as u64
+}
+
+/// `0 + 1 + ... + (n - 1)` modulo `m`.
+pub fn sum(n: u64, m: u128) -> u64 {
+    let n = n as u128;
+    // n (n - 1) < 2^128, and one of the two factors is even
+    let s = if n & 1 == 0 {
+        (n / 2) * n.saturating_sub(1)
+    } else {
+        n * (n.saturating_sub(1) / 2)
+    };
+    (s % m) as u64
+}
+
+/// Length of a longest common subsequence, one table row at a time.
+pub fn lcs_len(a: &[char], b: &[char]) -> usize {
+    let mut prev = vec![0; b.len() + 1];
+    for &x in a {
+        let mut row = vec![0; b.len() + 1];
+        for (j, &y) in b.iter().enumerate() {
+            row[j + 1] = if x == y {
+                prev[j] + 1
+            } else {
+                prev[j + 1].max(row[j])
+            };
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+fn is_subsequence(s: &[char], of: &[char]) -> bool {
+    let mut rest = of.iter();
+    s.iter().all(|c| rest.any(|d| d == c))
+}
+
+/// Checks `output` of `program` on `input`, as computed by `backend`.
+///
+/// Numbers must match exactly in the backend's arithmetic. There can be many
+/// longest common subsequences and the guests break ties differently, so an
+/// lcs output passes if it is a common subsequence of the longest length.
+pub fn check(backend: Backend, program: Program, input: &Input, output: &Output) -> Check {
+    let m = modulus(backend);
+    match program {
+        Program::Fib | Program::FastFib | Program::Sum => {
+            let n = (input.expect_n() as u128 % m) as u64;
+            let expected = match program {
+                Program::Sum => sum(input.expect_n(), m),
+                _ => fib(n, m),
+            };
+            Check {
+                passed: *output == Output::N(expected),
+                expected: expected.to_string(),
+            }
+        }
+        Program::Lcs | Program::Lcs2 => {
+            let (a, b) = input.expect_args();
+            let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+            let len = lcs_len(&a, &b);
+            let passed = match output {
+                Output::Str(s) => {
+                    let s: Vec<char> = s.chars().collect();
+                    s.len() == len && is_subsequence(&s, &a) && is_subsequence(&s, &b)
+                }
+                Output::N(_) => false,
+            };
+            Check {
+                passed,
+                expected: format!("a common subsequence of length {len}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WRAP: u128 = 1 << 64;
+
+    fn n(program: Program, backend: Backend, input: u64, output: u64) -> bool {
+        check(backend, program, &Input::N(input), &Output::N(output)).passed
+    }
+
+    fn lcs(a: &str, b: &str, output: &str) -> bool {
+        let input = Input::Args(a.to_string(), b.to_string());
+        check(
+            Backend::Sp1,
+            Program::Lcs,
+            &input,
+            &Output::Str(output.to_string()),
+        )
+        .passed
+    }
+
+    #[test]
+    fn fib_values() {
+        assert_eq!(fib(0, WRAP), 0);
+        assert_eq!(fib(1, WRAP), 1);
+        assert_eq!(fib(90, WRAP), 2880067194370816120);
+        // F(100) overflows a u64
+        assert_eq!(fib(100, WRAP), 3736710778780434371);
+        assert_eq!(fib(100, BABY_BEAR), 1584085617);
+    }
+
+    #[test]
+    fn modulus_depends_on_backend() {
+        for program in [Program::Fib, Program::FastFib] {
+            assert!(n(program, Backend::Sp1, 100, 3736710778780434371));
+            assert!(!n(program, Backend::Sp1, 100, 1584085617));
+            assert!(n(program, Backend::Lurk, 100, 1584085617));
+            assert!(!n(program, Backend::Lurk, 100, 3736710778780434371));
+        }
+        assert!(n(Program::Sum, Backend::Risc0, 100000, 4999950000));
+        assert!(n(Program::Sum, Backend::Lurk, 100000, 973418158));
+        assert!(!n(Program::Sum, Backend::Lurk, 100000, 4999950000));
+    }
+
+    #[test]
+    fn lurk_reads_inputs_modulo_its_prime() {
+        let p = BABY_BEAR as u64;
+        assert!(n(Program::Fib, Backend::Lurk, p, 0));
+        assert!(n(Program::Fib, Backend::Lurk, p + 1, 1));
+    }
+
+    #[test]
+    fn fastfib_boundaries() {
+        assert!(n(Program::FastFib, Backend::Sp1, 0, 0));
+        assert!(n(Program::FastFib, Backend::Sp1, 1, 1));
+        assert!(n(
+            Program::FastFib,
+            Backend::Sp1,
+            u64::MAX,
+            800812746651928290
+        ));
+        assert!(!n(Program::FastFib, Backend::Sp1, u64::MAX, 1));
+    }
+
+    #[test]
+    fn sum_boundaries() {
+        assert_eq!(sum(0, WRAP), 0);
+        assert_eq!(sum(1, WRAP), 0);
+        assert_eq!(
+            sum(u64::MAX, WRAP),
+            ((u64::MAX as u128 * (u64::MAX as u128 - 1) / 2) % WRAP) as u64
+        );
+    }
+
+    #[test]
+    fn lcs_accepts_any_tie_break() {
+        assert!(lcs("AB", "BA", "A"));
+        assert!(lcs("AB", "BA", "B"));
+        assert!(lcs("ABCBDAB", "BDCABA", "BCBA"));
+        assert!(lcs("ABCBDAB", "BDCABA", "BDAB"));
+        assert!(lcs("ABCBDAB", "BDCABA", "BCAB"));
+    }
+
+    #[test]
+    fn lcs_rejects_invalid_outputs() {
+        // too short
+        assert!(!lcs("AB", "BA", ""));
+        // not a subsequence of both
+        assert!(!lcs("AB", "BA", "AB"));
+        assert!(!lcs("ABCBDAB", "BDCABA", "ABBA"));
+        let input = Input::Args("AB".to_string(), "BA".to_string());
+        assert!(!check(Backend::Sp1, Program::Lcs2, &input, &Output::N(1)).passed);
+    }
+
+    #[test]
+    fn lcs_counts_chars() {
+        assert!(lcs("héllo", "hallö", "hll"));
+        assert!(lcs("日本語", "本語日", "本語"));
+    }
+}
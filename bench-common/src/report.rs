@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::backend::{Mode, Output};
+use crate::input::{Input, Program};
+use crate::measure::Measurement;
+use crate::reference::Check;
+use crate::stats::Summary;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    Jolt,
+    Lurk,
+    Risc0,
+    Sp1,
+    Sphinx,
+}
+
+impl Backend {
+    pub const ALL: [Backend; 5] = [
+        Backend::Jolt,
+        Backend::Lurk,
+        Backend::Risc0,
+        Backend::Sp1,
+        Backend::Sphinx,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Backend::Jolt => "jolt",
+            Backend::Lurk => "lurk",
+            Backend::Risc0 => "risc0",
+            Backend::Sp1 => "sp1",
+            Backend::Sphinx => "sphinx",
+        }
+    }
+}
+
+impl Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Backend::ALL
+            .into_iter()
+            .find(|b| b.name() == s)
+            .ok_or_else(|| format!("unknown backend {s:?}"))
+    }
+}
+
+/// Sharding options of the STARK-based provers (SP1, Sphinx, Lurk), and the
+/// segment size of RISC Zero. Backends without such options leave every
+/// field `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoreOpts {
+    pub shard_size: Option<usize>,
+    pub shard_batch_size: Option<usize>,
+    pub shard_chunking_multiplier: Option<usize>,
+    pub reconstruct_commitments: Option<bool>,
+    /// Log2 of the most cycles in one RISC Zero segment.
+    pub segment_limit_po2: Option<u32>,
+}
+
+/// Work counters, so that times can be normalized per unit of work. Each
+/// backend fills in the ones it has.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metrics {
+    /// Executed instructions (SP1, Sphinx), or total cycles including paging
+    /// and padding (RISC Zero).
+    pub cycles: Option<u64>,
+    /// RISC Zero cycles spent in guest code.
+    pub user_cycles: Option<u64>,
+    /// RISC Zero segments.
+    pub segments: Option<usize>,
+    /// Jolt execution trace length.
+    pub trace_length: Option<usize>,
+    /// Lurk `eval` invocations.
+    pub iterations: Option<usize>,
+}
+
+/// Every timed sample of a phase, in seconds, and their summary.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Phase {
+    pub samples: Vec<f64>,
+    #[serde(flatten)]
+    pub summary: Summary,
+    /// Highest peak resident memory over all samples.
+    pub peak_rss_bytes: Option<u64>,
+}
+
+impl Phase {
+    fn new(m: Measurement) -> Self {
+        Self {
+            samples: vec![m.secs],
+            summary: Summary::new(&[m.secs]),
+            peak_rss_bytes: m.peak_rss_bytes,
+        }
+    }
+
+    fn push(&mut self, m: Measurement) {
+        self.samples.push(m.secs);
+        self.summary = Summary::new(&self.samples);
+        self.peak_rss_bytes = self.peak_rss_bytes.max(m.peak_rss_bytes);
+    }
+}
+
+/// The one report every harness prints, as a single JSON line on stdout.
+///
+/// All fields are always present so that rows from different backends share
+/// one schema; anything a backend cannot measure is `null`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// `None` for ad hoc programs.
+    pub program: Option<Program>,
+    pub backend: Backend,
+    pub input: Input,
+    pub opts: CoreOpts,
+    /// SHA-256 of the guest ELF that was run (SP1, Sphinx), to tell which
+    /// build of the guest a result belongs to.
+    pub elf_sha256: Option<String>,
+    /// Source file of the program that was run, for backends that load one
+    /// at runtime (Lurk).
+    pub source: Option<String>,
+    /// Measured iterations per phase, not counting warm-up iterations.
+    pub repetitions: usize,
+    pub warmup: usize,
+    /// In `execute` mode there are no `prove` or `verify` phases and no
+    /// proof sizes.
+    pub mode: Mode,
+    /// Phase name (`setup`, `prove`, `verify`, ...) to its measurements.
+    pub phases: BTreeMap<String, Phase>,
+    pub output: Option<Output>,
+    /// The output compared with [`crate::reference`].
+    pub check: Option<Check>,
+    pub proof_bytes: Option<usize>,
+    pub vk_bytes: Option<usize>,
+    /// Size of the proof of each `prove` stage (`compress`, `shrink`, ...),
+    /// for backends that prove in stages.
+    pub stage_proof_bytes: BTreeMap<String, usize>,
+    pub metrics: Metrics,
+}
+
+impl Report {
+    pub fn new(program: Option<Program>, backend: Backend, input: Input) -> Self {
+        Self {
+            program,
+            backend,
+            input,
+            opts: CoreOpts::default(),
+            elf_sha256: None,
+            source: None,
+            repetitions: 0,
+            warmup: 0,
+            mode: Mode::Full,
+            phases: BTreeMap::new(),
+            output: None,
+            check: None,
+            proof_bytes: None,
+            vk_bytes: None,
+            stage_proof_bytes: BTreeMap::new(),
+            metrics: Metrics::default(),
+        }
+    }
+
+    /// Adds one sample to the phase's measurements.
+    pub fn phase(&mut self, name: &str, m: Measurement) {
+        match self.phases.get_mut(name) {
+            Some(phase) => phase.push(m),
+            None => {
+                self.phases.insert(name.to_string(), Phase::new(m));
+            }
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}", serde_json::to_string(self).unwrap());
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+/// Two-sided 95% critical values of Student's t distribution for 1 to 30
+/// degrees of freedom; larger samples use the normal approximation.
+const T95: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+const Z95: f64 = 1.960;
+
+/// Summary statistics of a set of samples.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+    pub mean: f64,
+    pub median: f64,
+    /// Sample standard deviation; zero for a single sample.
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+    /// 95% confidence interval of the mean; collapses to the mean for a
+    /// single sample.
+    pub ci95: (f64, f64),
+}
+
+impl Summary {
+    /// Panics on an empty slice.
+    pub fn new(samples: &[f64]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let n = samples.len();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        };
+
+        let (stddev, half_width) = if n > 1 {
+            let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            let stddev = var.sqrt();
+            let t = T95.get(n - 2).copied().unwrap_or(Z95);
+            (stddev, t * stddev / (n as f64).sqrt())
+        } else {
+            (0.0, 0.0)
+        };
+
+        Self {
+            mean,
+            median,
+            stddev,
+            min: sorted[0],
+            max: sorted[n - 1],
+            ci95: (mean - half_width, mean + half_width),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn single_sample() {
+        let s = Summary::new(&[5.0]);
+        assert_eq!((s.mean, s.median, s.stddev), (5.0, 5.0, 0.0));
+        assert_eq!((s.min, s.max), (5.0, 5.0));
+        assert_eq!(s.ci95, (5.0, 5.0));
+    }
+
+    #[test]
+    fn two_samples() {
+        let s = Summary::new(&[3.0, 1.0]);
+        assert_eq!((s.mean, s.median, s.min, s.max), (2.0, 2.0, 1.0, 3.0));
+        assert!(close(s.stddev, 2f64.sqrt()));
+        // one degree of freedom: t = 12.706, and stddev / sqrt(2) = 1
+        assert!(close(s.ci95.0, 2.0 - 12.706));
+        assert!(close(s.ci95.1, 2.0 + 12.706));
+    }
+
+    #[test]
+    fn four_samples() {
+        let s = Summary::new(&[4.0, 1.0, 3.0, 2.0]);
+        assert_eq!((s.mean, s.median), (2.5, 2.5));
+        let stddev = (5.0f64 / 3.0).sqrt();
+        assert!(close(s.stddev, stddev));
+        let half = 3.182 * stddev / 2.0;
+        assert!(close(s.ci95.0, 2.5 - half) && close(s.ci95.1, 2.5 + half));
+    }
+
+    #[test]
+    fn odd_median() {
+        assert_eq!(Summary::new(&[3.0, 1.0, 2.0]).median, 2.0);
+    }
+
+    #[test]
+    fn large_sample_uses_normal_approximation() {
+        let samples: Vec<f64> = (0..32).map(|i| (i % 2) as f64).collect();
+        let s = Summary::new(&samples);
+        let half = Z95 * s.stddev / 32f64.sqrt();
+        assert!(close(s.ci95.1 - s.mean, half));
+    }
+}
@@ -0,0 +1,116 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bench_common::{Backend, Input, Output, Program, ProofSizes, Report, ZkvmBackend};
+use jolt::host::analyze::ProgramSummary;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+/// Argument types of the `#[jolt::provable]` guest functions.
+pub trait JoltInput: Sized {
+    fn from_input(input: &Input) -> Self;
+}
+
+impl JoltInput for u64 {
+    fn from_input(input: &Input) -> Self {
+        input.expect_n()
+    }
+}
+
+impl JoltInput for Vec<u64> {
+    fn from_input(input: &Input) -> Self {
+        (0..input.expect_n()).collect()
+    }
+}
+
+impl JoltInput for (String, String) {
+    fn from_input(input: &Input) -> Self {
+        input.expect_args()
+    }
+}
+
+/// A copy of a Jolt proof, which is not `Clone`, through a serialization
+/// round trip.
+pub fn copy_proof<Pf: CanonicalSerialize + CanonicalDeserialize>(proof: &Pf) -> Pf {
+    let mut bytes = Vec::new();
+    proof.serialize_compressed(&mut bytes).unwrap();
+    Pf::deserialize_compressed(&bytes[..]).unwrap()
+}
+
+/// Jolt running one guest crate, driven through the `build_*` and
+/// `analyze_*` functions that `#[jolt::provable]` generates for it.
+pub struct Jolt<I, Build, Analyze> {
+    program: Program,
+    build: Build,
+    analyze: Analyze,
+    trace_length: Option<usize>,
+    _input: PhantomData<fn(I)>,
+}
+
+impl<I, Build, Analyze> Jolt<I, Build, Analyze>
+where
+    Analyze: Fn(I) -> ProgramSummary,
+{
+    pub fn new(program: Program, build: Build, analyze: Analyze) -> Self {
+        Self {
+            program,
+            build,
+            analyze,
+            trace_length: None,
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<I, O, Pf, P, V, Build, Analyze> ZkvmBackend for Jolt<I, Build, Analyze>
+where
+    I: JoltInput,
+    O: Into<Output> + DeserializeOwned,
+    Pf: CanonicalSerialize,
+    P: Fn(I) -> (O, Pf),
+    V: Fn(Pf) -> bool,
+    Build: Fn() -> (P, V),
+    Analyze: Fn(I) -> ProgramSummary,
+{
+    const BACKEND: Backend = Backend::Jolt;
+
+    type Setup = (P, V);
+    type Proof = Pf;
+
+    fn program(&self) -> Option<Program> {
+        Some(self.program)
+    }
+
+    fn setup(&mut self, _input: &Input) -> (P, V) {
+        (self.build)()
+    }
+
+    fn execute(&mut self, _setup: &mut (P, V), input: &Input) -> Output {
+        let summary = (self.analyze)(I::from_input(input));
+        self.trace_length = Some(summary.trace_len());
+        jolt::postcard::from_bytes::<O>(&summary.io_device.outputs)
+            .unwrap()
+            .into()
+    }
+
+    fn prove(&mut self, (prove, _): &mut (P, V), input: &Input) -> (Pf, Output) {
+        let (output, proof) = prove(I::from_input(input));
+        (proof, output.into())
+    }
+
+    fn verify(&mut self, (_, verify): &(P, V), proof: Pf) {
+        assert!(verify(proof), "verification failed");
+    }
+
+    /// The preprocessing the verifier needs is built into the `verify`
+    /// closure and never serialized.
+    fn proof_sizes(&self, _setup: &(P, V), proof: &Pf) -> ProofSizes {
+        ProofSizes {
+            proof_bytes: proof.compressed_size(),
+            vk_bytes: None,
+            stages: Vec::new(),
+        }
+    }
+
+    fn annotate(&self, report: &mut Report) {
+        report.metrics.trace_length = self.trace_length;
+    }
+}
@@ -0,0 +1,12 @@
+use bench_common::Program;
+use jolt_backend::{copy_proof, Jolt};
+
+// `cargo bench --bench fastfib`
+bench_common::criterion_phases!(
+    Jolt::new(
+        Program::FastFib,
+        guest::build_fastfib,
+        guest::analyze_fastfib
+    ),
+    copy_proof
+);
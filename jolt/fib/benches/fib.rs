@@ -0,0 +1,8 @@
+use bench_common::Program;
+use jolt_backend::{copy_proof, Jolt};
+
+// `cargo bench --bench fib`
+bench_common::criterion_phases!(
+    Jolt::new(Program::Fib, guest::build_fib, guest::analyze_fib),
+    copy_proof
+);
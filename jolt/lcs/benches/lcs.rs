@@ -0,0 +1,8 @@
+use bench_common::Program;
+use jolt_backend::{copy_proof, Jolt};
+
+// `cargo bench --bench lcs`
+bench_common::criterion_phases!(
+    Jolt::new(Program::Lcs, guest::build_lcs, guest::analyze_lcs),
+    copy_proof
+);
@@ -0,0 +1,8 @@
+use bench_common::Program;
+use jolt_backend::{copy_proof, Jolt};
+
+// `cargo bench --bench lcs2`
+bench_common::criterion_phases!(
+    Jolt::new(Program::Lcs2, guest::build_lcs2, guest::analyze_lcs2),
+    copy_proof
+);
@@ -0,0 +1,8 @@
+use bench_common::Program;
+use jolt_backend::{copy_proof, Jolt};
+
+// `cargo bench --bench sum`
+bench_common::criterion_phases!(
+    Jolt::new(Program::Sum, guest::build_sum, guest::analyze_sum),
+    copy_proof
+);
@@ -3,5 +3,5 @@
 
 #[jolt::provable(max_input_size = 100000001)]
 fn sum(input: Vec<u64>) -> u64 {
-    input.iter().sum()
+    bench_algos::sum(&input)
 }
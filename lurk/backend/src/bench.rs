@@ -0,0 +1,136 @@
+//! Criterion benchmarks of the stages of proving a Lurk expression, shared by
+//! the `benches/` target of every Lurk program.
+
+pub use bench_common::bench::{config, criterion};
+
+use bench_common::{Input, Program};
+use criterion::{BatchSize, Criterion};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use sphinx_core::{
+    air::MachineAir,
+    stark::{LocalProver, StarkGenericConfig, StarkMachine},
+    utils::BabyBearPoseidon2,
+};
+
+use loam::lair::{
+    execute::Shard,
+    lair_chip::{build_chip_vector, build_lair_chip_vector, LairMachineProgram},
+};
+use loam::lurk::eval::build_lurk_toplevel;
+
+use crate::{opts_from_env, setup, LurkSource};
+
+/// `{name}-evaluation`: running the Lurk evaluator on `source` applied to
+/// `input`.
+pub fn evaluation(c: &mut Criterion, name: &str, source: &LurkSource, input: &Input) {
+    c.bench_function(&format!("{name}-evaluation"), |b| {
+        let (toplevel, _) = build_lurk_toplevel();
+        let (args, lurk_main, record, _) = setup(source, input, &toplevel);
+        b.iter_batched(
+            || (args.clone(), record.clone()),
+            |(args, mut queries)| {
+                toplevel
+                    .execute(lurk_main.func(), &args, &mut queries, None)
+                    .unwrap();
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+/// `{name}-trace-generation`: generating the traces of every chip from an
+/// evaluated record.
+pub fn trace_generation(c: &mut Criterion, name: &str, source: &LurkSource, input: &Input) {
+    c.bench_function(&format!("{name}-trace-generation"), |b| {
+        let (toplevel, _) = build_lurk_toplevel();
+        let (args, lurk_main, mut record, _) = setup(source, input, &toplevel);
+        toplevel
+            .execute(lurk_main.func(), &args, &mut record, None)
+            .unwrap();
+        let lair_chips = build_lair_chip_vector(&lurk_main);
+        b.iter(|| {
+            lair_chips.par_iter().for_each(|func_chip| {
+                let shard = Shard::new(&record);
+                func_chip.generate_trace(&shard, &mut Default::default());
+            })
+        })
+    });
+}
+
+/// `{name}-e2e`: evaluation, machine setup and proving, with the options the
+/// `Lurk` backend would prove with.
+pub fn e2e(c: &mut Criterion, name: &str, source: &LurkSource, input: &Input) {
+    c.bench_function(&format!("{name}-e2e"), |b| {
+        let (toplevel, _) = build_lurk_toplevel();
+        let (args, lurk_main, record, _) = setup(source, input, &toplevel);
+        let opts = opts_from_env();
+
+        b.iter_batched(
+            || (record.clone(), args.clone()),
+            |(mut record, args)| {
+                toplevel
+                    .execute(lurk_main.func(), &args, &mut record, None)
+                    .unwrap();
+                let config = BabyBearPoseidon2::new();
+                let machine = StarkMachine::new(
+                    config,
+                    build_chip_vector(&lurk_main),
+                    record.expect_public_values().len(),
+                );
+                let (pk, _) = machine.setup(&LairMachineProgram);
+                let mut challenger_p = machine.config().challenger();
+                let shard = Shard::new(&record);
+                machine.prove::<LocalProver<_, _>>(&pk, shard, &mut challenger_p, opts);
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+/// The input of `program` when its environment variables are unset: the
+/// harness default, except that fib and lcs, naive exponential recursions in
+/// Lurk, get the smaller inputs the nightly suite gives them.
+pub fn default_input(program: Program) -> Input {
+    match program {
+        Program::Fib => Input::N(20),
+        Program::Lcs => Input::Args(
+            "When in the Course of human events".into(),
+            "There must be some kind of way".into(),
+        ),
+        _ => program.default_input(),
+    }
+}
+
+/// [`evaluation`], [`trace_generation`] and [`e2e`] of
+/// `lurk/programs/<program>.lurk` on the program's input from the environment.
+pub fn program_stages(c: &mut Criterion, program: Program) {
+    let source = LurkSource::program(program);
+    let input = program.input_from_env_or(default_input(program));
+    let name = program.name();
+    evaluation(c, name, &source, &input);
+    trace_generation(c, name, &source, &input);
+    e2e(c, name, &source, &input);
+}
+
+/// The whole `benches/` target of a Lurk program: [`program_stages`] of
+/// `$program` as the criterion `main`.
+///
+/// ```ignore
+/// lurk_backend::criterion_stages!(Program::Fib);
+/// ```
+#[macro_export]
+macro_rules! criterion_stages {
+    ($program:expr) => {
+        fn stages(c: &mut $crate::bench::criterion::Criterion) {
+            $crate::bench::program_stages(c, $program);
+        }
+
+        $crate::bench::criterion::criterion_group! {
+            name = benches;
+            config = $crate::bench::config();
+            targets = stages,
+        }
+
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
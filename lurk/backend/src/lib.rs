@@ -0,0 +1,356 @@
+use bench_common::{
+    env_or, Backend, CoreOpts, Input, Measurement, Output, PhaseMeter, Program, ProofSizes, Report,
+    ZkvmBackend,
+};
+use p3_baby_bear::BabyBear;
+use p3_field::AbstractField;
+use sphinx_core::{
+    stark::{LocalProver, MachineProof, StarkGenericConfig, StarkMachine, StarkVerifyingKey},
+    utils::{BabyBearPoseidon2, SphinxCoreOpts},
+};
+
+use loam::{
+    lair::{
+        chipset::Chipset,
+        execute::{QueryRecord, Shard},
+        func_chip::FuncChip,
+        lair_chip::{build_chip_vector, LairChip, LairMachineProgram},
+        toplevel::Toplevel,
+        List,
+    },
+    lurk::{
+        chipset::LurkChip,
+        eval::build_lurk_toplevel,
+        tag::Tag,
+        zstore::{lurk_zstore, ZPtr, ZStore},
+    },
+};
+
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod source;
+
+pub use source::LurkSource;
+
+#[allow(clippy::type_complexity)]
+pub fn setup<'a, H: Chipset<BabyBear>>(
+    source: &LurkSource,
+    input: &Input,
+    toplevel: &'a Toplevel<BabyBear, H>,
+) -> (
+    List<BabyBear>,
+    FuncChip<'a, BabyBear, H>,
+    QueryRecord<BabyBear>,
+    ZStore<BabyBear, LurkChip>,
+) {
+    let mut zstore = lurk_zstore();
+    let expr = source
+        .read(input, &mut zstore)
+        .unwrap_or_else(|e| panic!("{e}"));
+    prepare(expr, zstore, toplevel)
+}
+
+/// The `lurk_main` arguments and initial record for evaluating `expr`.
+#[allow(clippy::type_complexity)]
+fn prepare<'a, H: Chipset<BabyBear>>(
+    ZPtr { tag, digest }: ZPtr<BabyBear>,
+    zstore: ZStore<BabyBear, LurkChip>,
+    toplevel: &'a Toplevel<BabyBear, H>,
+) -> (
+    List<BabyBear>,
+    FuncChip<'a, BabyBear, H>,
+    QueryRecord<BabyBear>,
+    ZStore<BabyBear, LurkChip>,
+) {
+    let mut record = QueryRecord::new(toplevel);
+    record.inject_inv_queries("hash_32_8", toplevel, &zstore.hashes4);
+
+    let mut full_input = [BabyBear::zero(); 24];
+    full_input[0] = tag.to_field();
+    full_input[8..16].copy_from_slice(&digest);
+
+    let args: List<_> = full_input.into();
+    let lurk_main = FuncChip::from_name("lurk_main", toplevel);
+
+    (args, lurk_main, record, zstore)
+}
+
+/// Evaluates `lurk_main` on a copy of `record`, returning the filled-in record,
+/// the result as an [`Output`] and the number of `eval` invocations.
+fn evaluate(
+    toplevel: &Toplevel<BabyBear, LurkChip>,
+    setup: &mut LurkSetup,
+) -> (QueryRecord<BabyBear>, Output, usize) {
+    let lurk_main = FuncChip::from_name("lurk_main", toplevel);
+    let mut record = setup.record.clone();
+    let res = toplevel
+        .execute(lurk_main.func(), &setup.args, &mut record, None)
+        .unwrap();
+
+    let res = ZPtr::from_flat_data(&res);
+    setup.zstore.memoize_dag(
+        res.tag,
+        &res.digest,
+        record.get_inv_queries("hash_24_8", toplevel),
+        record.get_inv_queries("hash_32_8", toplevel),
+        record.get_inv_queries("hash_48_8", toplevel),
+    );
+    let eval_idx = toplevel.get_by_name("eval").index();
+    let iterations = record.func_queries()[eval_idx].len();
+    (record, output(&setup.zstore, &res), iterations)
+}
+
+/// Sphinx's default options, overridden by the same environment variables as
+/// the Sphinx harness reads (`SHARD_SIZE`, `SHARD_BATCH_SIZE`,
+/// `SHARD_CHUNKING_MULTIPLIER` and `RECONSTRUCT_COMMITMENTS`), so that Lurk
+/// does not depend on the Sphinx SDK for them.
+pub fn opts_from_env() -> SphinxCoreOpts {
+    let mut opts = SphinxCoreOpts::default();
+    opts.shard_size = env_or("SHARD_SIZE", opts.shard_size);
+    opts.shard_batch_size = env_or("SHARD_BATCH_SIZE", opts.shard_batch_size);
+    opts.shard_chunking_multiplier =
+        env_or("SHARD_CHUNKING_MULTIPLIER", opts.shard_chunking_multiplier);
+    opts.reconstruct_commitments = env_or("RECONSTRUCT_COMMITMENTS", opts.reconstruct_commitments);
+    opts
+}
+
+/// The Lurk evaluator, proven with Sphinx, running a program file on the
+/// benchmark input.
+///
+/// Setup times `parse` (reading the program into the store) and `args`
+/// (building the arguments in the store) on their own, and prove times
+/// `evaluate`, `keygen` (building the machine and its keys) and `stark` (the
+/// STARK proof itself).
+pub struct Lurk {
+    program: Option<Program>,
+    source: LurkSource,
+    /// From [`opts_from_env`]; the STARK is proven with these and they are
+    /// copied into the report.
+    opts: SphinxCoreOpts,
+    /// Built once, outside any measured phase, and leaked so that the machine
+    /// built from it can be carried in the proof.
+    toplevel: &'static Toplevel<BabyBear, LurkChip>,
+    iterations: Option<usize>,
+    stages: Vec<(&'static str, Measurement)>,
+}
+
+impl Lurk {
+    pub fn new(program: Program, source: LurkSource) -> Self {
+        Self::with_program(Some(program), source)
+    }
+
+    /// An ad hoc program, run on [`Input::Values`].
+    pub fn adhoc(source: LurkSource) -> Self {
+        Self::with_program(None, source)
+    }
+
+    fn with_program(program: Option<Program>, source: LurkSource) -> Self {
+        Self {
+            program,
+            source,
+            opts: opts_from_env(),
+            toplevel: Box::leak(Box::new(build_lurk_toplevel().0)),
+            iterations: None,
+            stages: Vec::new(),
+        }
+    }
+}
+
+/// Strings are read back from the store, since Lurk prints them quoted and
+/// escaped. Numbers are printed, `u64`s with a suffix, and anything else is
+/// kept as printed.
+fn output(zstore: &ZStore<BabyBear, LurkChip>, res: &ZPtr<BabyBear>) -> Output {
+    if res.tag == Tag::Str {
+        return Output::Str(zstore.fetch_string(res));
+    }
+    let printed = zstore.fmt(res);
+    printed
+        .trim_end_matches("u64")
+        .parse::<u64>()
+        .map(Output::N)
+        .unwrap_or(Output::Str(printed))
+}
+
+pub struct LurkSetup {
+    args: List<BabyBear>,
+    record: QueryRecord<BabyBear>,
+    zstore: ZStore<BabyBear, LurkChip>,
+}
+
+/// The machine the proof was made with, so that verification does not build
+/// it again.
+pub struct LurkProof {
+    machine: StarkMachine<BabyBearPoseidon2, LairChip<'static, BabyBear, LurkChip>>,
+    vk: StarkVerifyingKey<BabyBearPoseidon2>,
+    proof: MachineProof<BabyBearPoseidon2>,
+}
+
+impl ZkvmBackend for Lurk {
+    const BACKEND: Backend = Backend::Lurk;
+
+    type Setup = LurkSetup;
+    type Proof = LurkProof;
+
+    fn program(&self) -> Option<Program> {
+        self.program
+    }
+
+    fn name(&self) -> String {
+        match self.program {
+            Some(program) => program.to_string(),
+            None => self.source.name(),
+        }
+    }
+
+    fn setup(&mut self, input: &Input) -> LurkSetup {
+        let mut zstore = lurk_zstore();
+
+        let it = PhaseMeter::start();
+        let fun = self
+            .source
+            .read_program(&mut zstore)
+            .unwrap_or_else(|e| panic!("{e}"));
+        self.stages.push(("parse", it.stop()));
+
+        let it = PhaseMeter::start();
+        let expr = self
+            .source
+            .apply(fun, input, &mut zstore)
+            .unwrap_or_else(|e| panic!("{e}"));
+        self.stages.push(("args", it.stop()));
+
+        let (args, _, record, zstore) = prepare(expr, zstore, self.toplevel);
+        LurkSetup {
+            args,
+            record,
+            zstore,
+        }
+    }
+
+    fn execute(&mut self, setup: &mut LurkSetup, _input: &Input) -> Output {
+        let (_, output, iterations) = evaluate(self.toplevel, setup);
+        self.iterations = Some(iterations);
+        output
+    }
+
+    fn prove(&mut self, setup: &mut LurkSetup, _input: &Input) -> (LurkProof, Output) {
+        let toplevel = self.toplevel;
+        let mut stages = Vec::new();
+
+        let it = PhaseMeter::start();
+        let (record, output, _) = evaluate(toplevel, setup);
+        stages.push(("evaluate", it.stop()));
+
+        let it = PhaseMeter::start();
+        let lurk_main = FuncChip::from_name("lurk_main", toplevel);
+        let machine = StarkMachine::new(
+            BabyBearPoseidon2::new(),
+            build_chip_vector(&lurk_main),
+            record.expect_public_values().len(),
+        );
+        let (pk, vk) = machine.setup(&LairMachineProgram);
+        stages.push(("keygen", it.stop()));
+
+        let it = PhaseMeter::start();
+        let mut challenger_p = machine.config().challenger();
+        let shard = Shard::new(&record);
+        let proof = machine.prove::<LocalProver<_, _>>(&pk, shard, &mut challenger_p, self.opts);
+        stages.push(("stark", it.stop()));
+        let proof = LurkProof { machine, vk, proof };
+        self.stages.append(&mut stages);
+        (proof, output)
+    }
+
+    fn verify(&mut self, _setup: &LurkSetup, proof: LurkProof) {
+        let mut challenger_v = proof.machine.config().challenger();
+        proof
+            .machine
+            .verify(&proof.vk, &proof.proof, &mut challenger_v)
+            .expect("verification failed");
+    }
+
+    fn proof_sizes(&self, _setup: &LurkSetup, proof: &LurkProof) -> ProofSizes {
+        ProofSizes {
+            proof_bytes: bincode::serialized_size(&proof.proof).unwrap() as usize,
+            vk_bytes: Some(bincode::serialized_size(&proof.vk).unwrap() as usize),
+            stages: Vec::new(),
+        }
+    }
+
+    fn take_stages(&mut self) -> Vec<(&'static str, Measurement)> {
+        std::mem::take(&mut self.stages)
+    }
+
+    fn annotate(&self, report: &mut Report) {
+        report.opts = CoreOpts {
+            shard_size: Some(self.opts.shard_size),
+            shard_batch_size: Some(self.opts.shard_batch_size),
+            shard_chunking_multiplier: Some(self.opts.shard_chunking_multiplier),
+            reconstruct_commitments: Some(self.opts.reconstruct_commitments),
+            segment_limit_po2: None,
+        };
+        report.metrics.iterations = self.iterations;
+        report.source = Some(self.source.path.display().to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use source::LurkArg;
+    use std::path::Path;
+
+    const STRINGS: [&str; 4] = ["", "say \"hi\"", "back\\slash", "naïve ✓ 日本"];
+
+    #[test]
+    fn strings_round_trip_through_the_store() {
+        for s in STRINGS {
+            let mut zstore = lurk_zstore();
+            let ptr = LurkArg::Str(s.to_string()).intern(&mut zstore);
+            assert_eq!(output(&zstore, &ptr), Output::Str(s.to_string()), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn strings_round_trip_through_evaluation() {
+        let text = ";; params: s:string\n(lambda (s) s)\n".to_string();
+        let source = LurkSource::parse(Path::new("id.lurk"), text).unwrap();
+        let mut lurk = Lurk::adhoc(source);
+        for s in STRINGS {
+            let input = Input::Values(vec![s.to_string()]);
+            let mut setup = lurk.setup(&input);
+            let output = lurk.execute(&mut setup, &input);
+            assert_eq!(output, Output::Str(s.to_string()), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn lists_are_built_as_the_reader_reads_them() {
+        let mut zstore = lurk_zstore();
+        for ns in [vec![], vec![0], vec![0, 1, 2, 3]] {
+            let text = format!(
+                "(quote ({}))",
+                ns.iter().map(u64::to_string).collect::<Vec<_>>().join(" ")
+            );
+            let read = zstore.read(&text).unwrap();
+            assert_eq!(LurkArg::List(ns).intern(&mut zstore), read, "{text}");
+        }
+    }
+
+    #[test]
+    fn sum_of_a_built_list() {
+        let mut lurk = Lurk::new(Program::Sum, LurkSource::program(Program::Sum));
+        for (n, sum) in [(0, 0), (1, 0), (10, 45)] {
+            let input = Input::N(n);
+            let mut setup = lurk.setup(&input);
+            assert_eq!(lurk.execute(&mut setup, &input), Output::N(sum), "sum {n}");
+        }
+    }
+
+    #[test]
+    fn numbers_are_read_as_printed() {
+        let zstore = lurk_zstore();
+        let n = ZPtr::num(BabyBear::from_canonical_u64(55));
+        assert_eq!(output(&zstore, &n), Output::N(55));
+    }
+}
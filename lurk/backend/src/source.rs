@@ -0,0 +1,219 @@
+//! Lurk programs kept as `.lurk` files.
+//!
+//! A program file starts with a header declaring its parameters and their
+//! types, followed by an expression that evaluates to a function of those
+//! parameters:
+//!
+//! ```text
+//! ;; params: a:string b:string
+//! (letrec ((lcs (lambda (a b) ...)))
+//!   lcs)
+//! ```
+//!
+//! so the file can be loaded as is in the REPL. [`LurkSource::read`] applies
+//! the function to the arguments of a run.
+
+use bench_common::{Input, Program};
+use loam::lurk::{
+    chipset::LurkChip,
+    zstore::{ZPtr, ZStore},
+};
+use p3_baby_bear::BabyBear;
+use p3_field::AbstractField;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const HEADER: &str = ";; params:";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParamType {
+    Num,
+    String,
+    /// A list of numbers.
+    List,
+}
+
+impl FromStr for ParamType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "num" => Ok(ParamType::Num),
+            "string" => Ok(ParamType::String),
+            "list" => Ok(ParamType::List),
+            _ => Err(format!("unknown parameter type {s:?}")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub ty: ParamType,
+}
+
+/// Lurk reads numbers modulo the BabyBear prime.
+fn num(n: u64) -> ZPtr<BabyBear> {
+    ZPtr::num(BabyBear::from_wrapped_u64(n))
+}
+
+/// A value passed for a [`Param`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LurkArg {
+    Num(u64),
+    Str(String),
+    List(Vec<u64>),
+}
+
+impl LurkArg {
+    fn parse(ty: ParamType, s: &str) -> Result<Self, String> {
+        let num = |s: &str| {
+            s.trim()
+                .parse::<u64>()
+                .map_err(|_| format!("{s:?} is not a u64"))
+        };
+        match ty {
+            ParamType::Num => num(s).map(LurkArg::Num),
+            ParamType::String => Ok(LurkArg::Str(s.to_string())),
+            ParamType::List if s.trim().is_empty() => Ok(LurkArg::List(Vec::new())),
+            ParamType::List => s
+                .split(',')
+                .map(num)
+                .collect::<Result<_, _>>()
+                .map(LurkArg::List),
+        }
+    }
+
+    /// The argument as an expression evaluating to itself, built in the store
+    /// rather than printed and read back: strings go through unchanged, quotes,
+    /// backslashes and any Unicode included, and long lists skip the reader,
+    /// which would otherwise dominate setup.
+    pub(crate) fn intern(&self, zstore: &mut ZStore<BabyBear, LurkChip>) -> ZPtr<BabyBear> {
+        match self {
+            LurkArg::Num(n) => num(*n),
+            LurkArg::Str(s) => zstore.intern_string(s),
+            LurkArg::List(ns) => {
+                let list = zstore.intern_list(ns.iter().map(|&n| num(n)).collect::<Vec<_>>());
+                let quote = zstore.read("quote").unwrap();
+                zstore.intern_list(vec![quote, list])
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LurkSource {
+    pub path: PathBuf,
+    pub params: Vec<Param>,
+    /// The whole file, header included.
+    pub text: String,
+}
+
+impl LurkSource {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Self::parse(path, text)
+    }
+
+    pub fn parse(path: &Path, text: String) -> Result<Self, String> {
+        let header = text
+            .lines()
+            .next()
+            .and_then(|l| l.strip_prefix(HEADER))
+            .ok_or_else(|| format!("{} does not start with {HEADER:?}", path.display()))?;
+        let params = header
+            .split_whitespace()
+            .map(|p| {
+                let (name, ty) = p
+                    .split_once(':')
+                    .ok_or_else(|| format!("parameter {p:?} has no type"))?;
+                Ok(Param {
+                    name: name.to_string(),
+                    ty: ty.parse()?,
+                })
+            })
+            .collect::<Result<_, String>>()
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            params,
+            text,
+        })
+    }
+
+    /// `lurk/programs/<program>.lurk`.
+    pub fn program(program: Program) -> Self {
+        let lurk = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let path = lurk.join("programs").join(format!("{program}.lurk"));
+        Self::load(path).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// The file name without its extension.
+    pub fn name(&self) -> String {
+        self.path
+            .file_stem()
+            .map_or(String::new(), |s| s.to_string_lossy().into_owned())
+    }
+
+    /// Matches `input` with the declared parameters. A benchmark number given
+    /// for a list parameter stands for the list `0, 1, ..., n - 1`, the input
+    /// of sum on every backend.
+    pub fn args(&self, input: &Input) -> Result<Vec<LurkArg>, String> {
+        let types: Vec<_> = self.params.iter().map(|p| p.ty).collect();
+        match (input, &types[..]) {
+            (Input::N(n), [ParamType::Num]) => Ok(vec![LurkArg::Num(*n)]),
+            (Input::N(n), [ParamType::List]) => Ok(vec![LurkArg::List((0..*n).collect())]),
+            (Input::Args(a, b), [ParamType::String, ParamType::String]) => {
+                Ok(vec![LurkArg::Str(a.clone()), LurkArg::Str(b.clone())])
+            }
+            (Input::Values(vs), _) if vs.len() == types.len() => types
+                .iter()
+                .zip(vs)
+                .map(|(&ty, v)| LurkArg::parse(ty, v))
+                .collect(),
+            _ => Err(format!(
+                "{} takes {} argument(s), not {input:?}",
+                self.path.display(),
+                self.params.len()
+            )),
+        }
+    }
+
+    /// Reads the program into `zstore` and returns the expression applying it
+    /// to the arguments `input` gives.
+    pub fn read(
+        &self,
+        input: &Input,
+        zstore: &mut ZStore<BabyBear, LurkChip>,
+    ) -> Result<ZPtr<BabyBear>, String> {
+        let fun = self.read_program(zstore)?;
+        self.apply(fun, input, zstore)
+    }
+
+    /// Reads the program, the only part of the expression that goes through
+    /// the reader.
+    pub fn read_program(
+        &self,
+        zstore: &mut ZStore<BabyBear, LurkChip>,
+    ) -> Result<ZPtr<BabyBear>, String> {
+        zstore
+            .read(&self.text)
+            .map_err(|e| format!("could not read {}: {e:?}", self.path.display()))
+    }
+
+    /// The application of `fun`, the program as read, to the arguments
+    /// `input` gives, built in the store.
+    pub fn apply(
+        &self,
+        fun: ZPtr<BabyBear>,
+        input: &Input,
+        zstore: &mut ZStore<BabyBear, LurkChip>,
+    ) -> Result<ZPtr<BabyBear>, String> {
+        let args = self.args(input)?;
+        let mut expr = vec![fun];
+        expr.extend(args.iter().map(|arg| arg.intern(zstore)));
+        Ok(zstore.intern_list(expr))
+    }
+}
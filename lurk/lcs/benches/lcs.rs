@@ -0,0 +1,4 @@
+use bench_common::Program;
+
+// `cargo criterion --bench lcs`
+lurk_backend::criterion_stages!(Program::Lcs);
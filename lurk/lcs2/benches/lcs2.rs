@@ -0,0 +1,4 @@
+use bench_common::Program;
+
+// `cargo criterion --bench lcs2`
+lurk_backend::criterion_stages!(Program::Lcs2);
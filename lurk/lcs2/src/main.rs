@@ -0,0 +1,7 @@
+use bench_common::Program;
+use lurk_backend::{Lurk, LurkSource};
+
+fn main() {
+    let backend = Lurk::new(Program::Lcs2, LurkSource::program(Program::Lcs2));
+    bench_common::run(backend, Program::Lcs2.input_from_env()).print();
+}
@@ -0,0 +1,17 @@
+//! Runs any `.lurk` program file through the Lurk backend:
+//!
+//! ```text
+//! cargo run --release -p lurk-run -- <file.lurk> [ARG]...
+//! ```
+//!
+//! with one argument per parameter of the file's header.
+
+use bench_common::Input;
+use lurk_backend::{Lurk, LurkSource};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().expect("usage: lurk-run <file.lurk> [ARG]...");
+    let source = LurkSource::load(&path).unwrap_or_else(|e| panic!("{e}"));
+    bench_common::run(Lurk::adhoc(source), Input::Values(args.collect())).print();
+}
@@ -0,0 +1,4 @@
+use bench_common::Program;
+
+// `cargo criterion --bench sum`
+lurk_backend::criterion_stages!(Program::Sum);
@@ -0,0 +1,235 @@
+use bench_common::{
+    env_or, Backend, CoreOpts, Input, Measurement, Metrics, Output, PhaseMeter, Program,
+    ProofSizes, Report, ZkvmBackend,
+};
+use risc0_zkvm::{
+    default_executor, default_prover, get_prover_server, ExecutorEnv, InnerReceipt, Journal,
+    Prover, ProverOpts, Receipt,
+};
+use std::fmt::{self, Display};
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// The segment limit `risc0-zkvm` uses when none is given.
+const DEFAULT_SEGMENT_LIMIT_PO2: u32 = 20;
+
+/// The receipt prove ends with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReceiptKind {
+    /// One STARK per segment.
+    #[default]
+    Composite,
+    /// The segment STARKs lifted and joined into one.
+    Succinct,
+}
+
+impl ReceiptKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ReceiptKind::Composite => "composite",
+            ReceiptKind::Succinct => "succinct",
+        }
+    }
+}
+
+impl Display for ReceiptKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ReceiptKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [ReceiptKind::Composite, ReceiptKind::Succinct]
+            .into_iter()
+            .find(|k| k.name() == s)
+            .ok_or_else(|| format!("unknown receipt kind {s:?}"))
+    }
+}
+
+/// RISC Zero running one of the `*/methods/guest` programs.
+///
+/// Prove times the `composite` stage, proving every segment, and for succinct
+/// receipts the `lift` stage, turning each segment receipt into a recursion
+/// receipt, and the `join` stage, folding those into one.
+pub struct Risc0 {
+    program: Program,
+    elf: &'static [u8],
+    image_id: [u32; 8],
+    receipt_kind: ReceiptKind,
+    segment_limit_po2: u32,
+    metrics: Metrics,
+    stages: Vec<(&'static str, Measurement)>,
+    /// Size of the receipt of each stage of the last proof.
+    stage_bytes: Vec<(&'static str, usize)>,
+}
+
+impl Risc0 {
+    /// Proves the receipt kind `RISC0_RECEIPT` (`composite` or `succinct`)
+    /// with segments of at most `2^RISC0_SEGMENT_PO2` cycles.
+    pub fn new(program: Program, elf: &'static [u8], image_id: [u32; 8]) -> Self {
+        Self::with_opts(
+            program,
+            elf,
+            image_id,
+            env_or("RISC0_RECEIPT", ReceiptKind::default()),
+            env_or("RISC0_SEGMENT_PO2", DEFAULT_SEGMENT_LIMIT_PO2),
+        )
+    }
+
+    pub fn with_opts(
+        program: Program,
+        elf: &'static [u8],
+        image_id: [u32; 8],
+        receipt_kind: ReceiptKind,
+        segment_limit_po2: u32,
+    ) -> Self {
+        Self {
+            program,
+            elf,
+            image_id,
+            receipt_kind,
+            segment_limit_po2,
+            metrics: Metrics::default(),
+            stages: Vec::new(),
+            stage_bytes: Vec::new(),
+        }
+    }
+
+    fn env(&self, input: &Input) -> ExecutorEnv<'static> {
+        let mut builder = ExecutorEnv::builder();
+        builder.segment_limit_po2(self.segment_limit_po2);
+        match self.program {
+            Program::Fib | Program::FastFib => builder.write(&input.expect_n()),
+            Program::Sum => builder.write(&(0..input.expect_n()).collect::<Vec<u64>>()),
+            Program::Lcs | Program::Lcs2 => builder.write(&input.expect_args()),
+        }
+        .unwrap();
+        builder.build().unwrap()
+    }
+
+    /// Every guest commits an `(input, output)` pair.
+    fn decode_output(&self, journal: &Journal, input: &Input) -> Output {
+        match self.program {
+            Program::Fib | Program::FastFib => {
+                let (n, res): (u64, u64) = journal.decode().unwrap();
+                assert_eq!(n, input.expect_n());
+                res.into()
+            }
+            Program::Sum => {
+                let (nums, res): (Vec<u64>, u64) = journal.decode().unwrap();
+                assert_eq!(nums.len() as u64, input.expect_n());
+                res.into()
+            }
+            Program::Lcs | Program::Lcs2 => {
+                let (args, res): ((String, String), String) = journal.decode().unwrap();
+                assert_eq!(args, input.expect_args());
+                res.into()
+            }
+        }
+    }
+}
+
+impl ZkvmBackend for Risc0 {
+    const BACKEND: Backend = Backend::Risc0;
+
+    type Setup = Rc<dyn Prover>;
+    type Proof = Receipt;
+
+    fn program(&self) -> Option<Program> {
+        Some(self.program)
+    }
+
+    fn setup(&mut self, _input: &Input) -> Rc<dyn Prover> {
+        default_prover()
+    }
+
+    fn execute(&mut self, _prover: &mut Rc<dyn Prover>, input: &Input) -> Output {
+        let session = default_executor()
+            .execute(self.env(input), self.elf)
+            .unwrap();
+        // Segments are padded to a power of two, as in the prover's total.
+        self.metrics.cycles = Some(session.segments.iter().map(|s| 1u64 << s.po2).sum());
+        self.metrics.user_cycles = Some(session.segments.iter().map(|s| s.cycles as u64).sum());
+        self.metrics.segments = Some(session.segments.len());
+        self.decode_output(&session.journal, input)
+    }
+
+    fn prove(&mut self, prover: &mut Rc<dyn Prover>, input: &Input) -> (Receipt, Output) {
+        let env = self.env(input);
+        let it = PhaseMeter::start();
+        let prove_info = prover
+            .prove_with_opts(env, self.elf, &ProverOpts::composite())
+            .unwrap();
+        self.stages.push(("composite", it.stop()));
+        self.metrics = Metrics {
+            cycles: Some(prove_info.stats.total_cycles),
+            user_cycles: Some(prove_info.stats.user_cycles),
+            segments: Some(prove_info.stats.segments),
+            ..Metrics::default()
+        };
+        let mut receipt = prove_info.receipt;
+        self.stage_bytes = vec![(
+            "composite",
+            bincode::serialized_size(&receipt).unwrap() as usize,
+        )];
+
+        // What `Prover::compress` does, split so lift and join are timed on
+        // their own. The guests make no assumptions, so nothing is resolved.
+        if self.receipt_kind == ReceiptKind::Succinct {
+            let server = get_prover_server(&ProverOpts::succinct()).unwrap();
+            let segments = &receipt.inner.composite().unwrap().segments;
+
+            let it = PhaseMeter::start();
+            let lifted: Vec<_> = segments.iter().map(|s| server.lift(s).unwrap()).collect();
+            self.stages.push(("lift", it.stop()));
+            self.stage_bytes.push((
+                "lift",
+                lifted
+                    .iter()
+                    .map(|r| bincode::serialized_size(r).unwrap() as usize)
+                    .sum(),
+            ));
+
+            let it = PhaseMeter::start();
+            let joined = lifted
+                .into_iter()
+                .reduce(|a, b| server.join(&a, &b).unwrap())
+                .unwrap();
+            self.stages.push(("join", it.stop()));
+            receipt = Receipt::new(InnerReceipt::Succinct(joined), receipt.journal.bytes);
+            self.stage_bytes
+                .push(("join", bincode::serialized_size(&receipt).unwrap() as usize));
+        }
+
+        let output = self.decode_output(&receipt.journal, input);
+        (receipt, output)
+    }
+
+    fn verify(&mut self, _prover: &Rc<dyn Prover>, receipt: Receipt) {
+        receipt.verify(self.image_id).unwrap();
+    }
+
+    /// The verifier's only program-specific input is the image ID.
+    fn proof_sizes(&self, _prover: &Rc<dyn Prover>, receipt: &Receipt) -> ProofSizes {
+        ProofSizes {
+            proof_bytes: bincode::serialized_size(receipt).unwrap() as usize,
+            vk_bytes: Some(std::mem::size_of_val(&self.image_id)),
+            stages: self.stage_bytes.clone(),
+        }
+    }
+
+    fn take_stages(&mut self) -> Vec<(&'static str, Measurement)> {
+        std::mem::take(&mut self.stages)
+    }
+
+    fn annotate(&self, report: &mut Report) {
+        report.opts = CoreOpts {
+            segment_limit_po2: Some(self.segment_limit_po2),
+            ..CoreOpts::default()
+        };
+        report.metrics = self.metrics.clone();
+    }
+}
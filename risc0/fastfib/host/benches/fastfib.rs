@@ -0,0 +1,13 @@
+use bench_common::Program;
+use methods::{GUEST_RISC0_FASTFIB_ELF, GUEST_RISC0_FASTFIB_ID};
+use risc0_backend::Risc0;
+
+// `cargo bench --bench fastfib`
+bench_common::criterion_phases!(
+    Risc0::new(
+        Program::FastFib,
+        GUEST_RISC0_FASTFIB_ELF,
+        GUEST_RISC0_FASTFIB_ID
+    ),
+    Clone::clone
+);
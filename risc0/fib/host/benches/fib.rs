@@ -0,0 +1,9 @@
+use bench_common::Program;
+use methods::{GUEST_RISC0_FIB_ELF, GUEST_RISC0_FIB_ID};
+use risc0_backend::Risc0;
+
+// `cargo bench --bench fib`
+bench_common::criterion_phases!(
+    Risc0::new(Program::Fib, GUEST_RISC0_FIB_ELF, GUEST_RISC0_FIB_ID),
+    Clone::clone
+);
@@ -0,0 +1,9 @@
+use bench_common::Program;
+use methods::{GUEST_RISC0_LCS_ELF, GUEST_RISC0_LCS_ID};
+use risc0_backend::Risc0;
+
+// `cargo bench --bench lcs`
+bench_common::criterion_phases!(
+    Risc0::new(Program::Lcs, GUEST_RISC0_LCS_ELF, GUEST_RISC0_LCS_ID),
+    Clone::clone
+);
@@ -0,0 +1,9 @@
+use bench_common::Program;
+use methods::{GUEST_RISC0_LCS2_ELF, GUEST_RISC0_LCS2_ID};
+use risc0_backend::Risc0;
+
+// `cargo bench --bench lcs2`
+bench_common::criterion_phases!(
+    Risc0::new(Program::Lcs2, GUEST_RISC0_LCS2_ELF, GUEST_RISC0_LCS2_ID),
+    Clone::clone
+);
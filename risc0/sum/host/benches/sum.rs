@@ -0,0 +1,9 @@
+use bench_common::Program;
+use methods::{GUEST_RISC0_SUM_ELF, GUEST_RISC0_SUM_ID};
+use risc0_backend::Risc0;
+
+// `cargo bench --bench sum`
+bench_common::criterion_phases!(
+    Risc0::new(Program::Sum, GUEST_RISC0_SUM_ELF, GUEST_RISC0_SUM_ID),
+    Clone::clone
+);
@@ -3,7 +3,7 @@ use risc0_zkvm::guest::env;
 fn main() {
     let input: Vec<u64> = env::read();
 
-    let output: u64 = input.iter().sum();
+    let output: u64 = bench_algos::sum(&input);
 
     env::commit(&(input, output));
 }
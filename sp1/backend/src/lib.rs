@@ -0,0 +1,264 @@
+use bench_common::{
+    env_or, Backend, CoreOpts, Input, Measurement, Output, PhaseMeter, Program, ProofSizes, Report,
+    ZkvmBackend,
+};
+use sha2::{Digest, Sha256};
+use sp1_prover::{InnerSC, SP1CoreProof, SP1ReduceProof};
+use sp1_sdk::{
+    Prover, ProverClient, SP1Context, SP1ProofWithPublicValues, SP1ProvingKey, SP1PublicValues,
+    SP1Stdin, SP1VerifyingKey,
+};
+use sp1_stark::{SP1CoreOpts, SP1ProverOpts};
+
+/// SP1's default options, overridden by the `SHARD_SIZE`, `SHARD_BATCH_SIZE`
+/// and `RECONSTRUCT_COMMITMENTS` environment variables.
+pub fn opts_from_env() -> SP1CoreOpts {
+    let mut opts = SP1CoreOpts::default();
+    opts.shard_size = env_or("SHARD_SIZE", opts.shard_size);
+    opts.shard_batch_size = env_or("SHARD_BATCH_SIZE", opts.shard_batch_size);
+    opts.reconstruct_commitments = env_or("RECONSTRUCT_COMMITMENTS", opts.reconstruct_commitments);
+    opts
+}
+
+/// SP1 running one of the `*/program` guests.
+///
+/// By default prove makes the core proof through the SDK. With `compress`
+/// it goes on to the recursion stages, proving and verifying `core`,
+/// `compress` and `shrink` as separately timed stages.
+pub struct Sp1 {
+    program: Program,
+    elf: &'static [u8],
+    /// Given to the SDK prover and copied into the report's `opts`.
+    opts: SP1CoreOpts,
+    compress: bool,
+    cycles: Option<u64>,
+    stages: Vec<(&'static str, Measurement)>,
+}
+
+impl Sp1 {
+    /// Proves with [`opts_from_env`], compressing if `SP1_COMPRESS` is true.
+    pub fn new(program: Program, elf: &'static [u8]) -> Self {
+        Self::with_opts(program, elf, opts_from_env(), env_or("SP1_COMPRESS", false))
+    }
+
+    pub fn with_opts(
+        program: Program,
+        elf: &'static [u8],
+        opts: SP1CoreOpts,
+        compress: bool,
+    ) -> Self {
+        Self {
+            program,
+            elf,
+            opts,
+            compress,
+            cycles: None,
+            stages: Vec::new(),
+        }
+    }
+
+    fn stdin(&self, input: &Input) -> SP1Stdin {
+        let mut stdin = SP1Stdin::new();
+        match self.program {
+            Program::Fib | Program::FastFib => stdin.write(&input.expect_n()),
+            Program::Sum => stdin.write(&(0..input.expect_n()).collect::<Vec<u64>>()),
+            Program::Lcs | Program::Lcs2 => stdin.write(&input.expect_args()),
+        }
+        stdin
+    }
+
+    /// Every guest commits its input followed by its output.
+    fn read_output(&self, public_values: &mut SP1PublicValues, input: &Input) -> Output {
+        match self.program {
+            Program::Fib | Program::FastFib => {
+                assert_eq!(public_values.read::<u64>(), input.expect_n());
+                public_values.read::<u64>().into()
+            }
+            Program::Sum => {
+                let nums = public_values.read::<Vec<u64>>();
+                assert_eq!(nums.len() as u64, input.expect_n());
+                public_values.read::<u64>().into()
+            }
+            Program::Lcs | Program::Lcs2 => {
+                assert_eq!(
+                    public_values.read::<(String, String)>(),
+                    input.expect_args()
+                );
+                public_values.read::<String>().into()
+            }
+        }
+    }
+
+    /// Proves stage by stage with the SDK's prover, which the SDK's own
+    /// compressed mode would run in one go.
+    fn prove_compressed(&mut self, setup: &Sp1Setup, input: &Input) -> (Sp1Proof, Output) {
+        let prover = setup.client.prover.sp1_prover();
+        let opts = SP1ProverOpts {
+            core_opts: self.opts,
+            ..Default::default()
+        };
+        let stdin = self.stdin(input);
+
+        let it = PhaseMeter::start();
+        let core = prover
+            .prove_core(&setup.pk, &stdin, opts, SP1Context::default())
+            .unwrap();
+        self.stages.push(("core", it.stop()));
+
+        let it = PhaseMeter::start();
+        let compress = prover
+            .compress(&setup.vk, core.clone(), Vec::new(), opts)
+            .unwrap();
+        self.stages.push(("compress", it.stop()));
+
+        let it = PhaseMeter::start();
+        let shrink = prover.shrink(compress.clone(), opts).unwrap();
+        self.stages.push(("shrink", it.stop()));
+
+        let output = self.read_output(&mut core.public_values.clone(), input);
+        let proof = Sp1Proof::Compressed {
+            core,
+            compress,
+            shrink,
+        };
+        (proof, output)
+    }
+}
+
+/// The SDK's core proof, or the proof of every stage when compressing.
+#[derive(Clone)]
+pub enum Sp1Proof {
+    Core(SP1ProofWithPublicValues),
+    Compressed {
+        core: SP1CoreProof,
+        compress: SP1ReduceProof<InnerSC>,
+        shrink: SP1ReduceProof<InnerSC>,
+    },
+}
+
+pub struct Sp1Setup {
+    client: ProverClient,
+    pk: SP1ProvingKey,
+    vk: SP1VerifyingKey,
+}
+
+impl ZkvmBackend for Sp1 {
+    const BACKEND: Backend = Backend::Sp1;
+
+    type Setup = Sp1Setup;
+    type Proof = Sp1Proof;
+
+    fn program(&self) -> Option<Program> {
+        Some(self.program)
+    }
+
+    fn setup(&mut self, _input: &Input) -> Sp1Setup {
+        let client = ProverClient::new();
+        let (pk, vk) = client.setup(self.elf);
+        Sp1Setup { client, pk, vk }
+    }
+
+    fn execute(&mut self, setup: &mut Sp1Setup, input: &Input) -> Output {
+        let (mut public_values, report) = setup
+            .client
+            .execute(self.elf, self.stdin(input))
+            .run()
+            .unwrap();
+        self.cycles = Some(report.total_instruction_count());
+        self.read_output(&mut public_values, input)
+    }
+
+    fn prove(&mut self, setup: &mut Sp1Setup, input: &Input) -> (Self::Proof, Output) {
+        if self.compress {
+            return self.prove_compressed(setup, input);
+        }
+        let proof = setup
+            .client
+            .prove(&setup.pk, self.stdin(input))
+            .shard_size(self.opts.shard_size)
+            .shard_batch_size(self.opts.shard_batch_size)
+            .reconstruct_commitments(self.opts.reconstruct_commitments)
+            .run()
+            .unwrap();
+        let output = self.read_output(&mut proof.public_values.clone(), input);
+        (Sp1Proof::Core(proof), output)
+    }
+
+    fn verify(&mut self, setup: &Sp1Setup, proof: Self::Proof) {
+        match proof {
+            Sp1Proof::Core(proof) => setup
+                .client
+                .verify(&proof, &setup.vk)
+                .expect("verification failed"),
+            Sp1Proof::Compressed {
+                core,
+                compress,
+                shrink,
+            } => {
+                let prover = setup.client.prover.sp1_prover();
+                let it = PhaseMeter::start();
+                prover
+                    .verify(&core.proof, &setup.vk)
+                    .expect("core verification failed");
+                self.stages.push(("core", it.stop()));
+
+                let it = PhaseMeter::start();
+                prover
+                    .verify_compressed(&compress, &setup.vk)
+                    .expect("compress verification failed");
+                self.stages.push(("compress", it.stop()));
+
+                let it = PhaseMeter::start();
+                prover
+                    .verify_shrink(&shrink, &setup.vk)
+                    .expect("shrink verification failed");
+                self.stages.push(("shrink", it.stop()));
+            }
+        }
+    }
+
+    /// The compressed proof is the one reported as `proof_bytes`, as it is
+    /// what gets shipped.
+    fn proof_sizes(&self, setup: &Sp1Setup, proof: &Self::Proof) -> ProofSizes {
+        let vk_bytes = Some(bincode::serialized_size(&setup.vk).unwrap() as usize);
+        match proof {
+            Sp1Proof::Core(proof) => ProofSizes {
+                proof_bytes: bincode::serialized_size(proof).unwrap() as usize,
+                vk_bytes,
+                stages: Vec::new(),
+            },
+            Sp1Proof::Compressed {
+                core,
+                compress,
+                shrink,
+            } => ProofSizes {
+                proof_bytes: bincode::serialized_size(compress).unwrap() as usize,
+                vk_bytes,
+                stages: vec![
+                    ("core", bincode::serialized_size(core).unwrap() as usize),
+                    (
+                        "compress",
+                        bincode::serialized_size(compress).unwrap() as usize,
+                    ),
+                    ("shrink", bincode::serialized_size(shrink).unwrap() as usize),
+                ],
+            },
+        }
+    }
+
+    fn take_stages(&mut self) -> Vec<(&'static str, Measurement)> {
+        std::mem::take(&mut self.stages)
+    }
+
+    fn annotate(&self, report: &mut Report) {
+        report.opts = CoreOpts {
+            shard_size: Some(self.opts.shard_size),
+            shard_batch_size: Some(self.opts.shard_batch_size),
+            shard_chunking_multiplier: None,
+            reconstruct_commitments: Some(self.opts.reconstruct_commitments),
+            segment_limit_po2: None,
+        };
+        report.metrics.cycles = self.cycles;
+        report.elf_sha256 = Some(hex::encode(Sha256::digest(self.elf)));
+    }
+}
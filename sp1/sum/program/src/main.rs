@@ -4,7 +4,7 @@ sp1_zkvm::entrypoint!(main);
 pub fn main() {
     let input = sp1_zkvm::io::read::<Vec<u64>>();
 
-    let output: u64 = input.iter().sum();
+    let output: u64 = bench_algos::sum(&input);
 
     sp1_zkvm::io::commit(&input);
     sp1_zkvm::io::commit(&output);
@@ -0,0 +1,295 @@
+use bench_common::{
+    env_or, Backend, CoreOpts, Input, Measurement, Output, PhaseMeter, Program, ProofSizes, Report,
+    ZkvmBackend,
+};
+use sha2::{Digest, Sha256};
+use sphinx_core::runtime::SphinxContext;
+use sphinx_core::utils::{SphinxCoreOpts, SphinxProverOpts};
+use sphinx_prover::{InnerSC, OuterSC, SphinxCoreProof, SphinxReduceProof};
+use sphinx_sdk::{
+    Prover, ProverClient, SphinxProofWithPublicValues, SphinxProvingKey, SphinxPublicValues,
+    SphinxStdin, SphinxVerifyingKey,
+};
+
+/// Sphinx's default options, overridden by the `SHARD_SIZE`,
+/// `SHARD_BATCH_SIZE`, `SHARD_CHUNKING_MULTIPLIER` and
+/// `RECONSTRUCT_COMMITMENTS` environment variables.
+pub fn opts_from_env() -> SphinxCoreOpts {
+    let mut opts = SphinxCoreOpts::default();
+    opts.shard_size = env_or("SHARD_SIZE", opts.shard_size);
+    opts.shard_batch_size = env_or("SHARD_BATCH_SIZE", opts.shard_batch_size);
+    opts.shard_chunking_multiplier =
+        env_or("SHARD_CHUNKING_MULTIPLIER", opts.shard_chunking_multiplier);
+    opts.reconstruct_commitments = env_or("RECONSTRUCT_COMMITMENTS", opts.reconstruct_commitments);
+    opts
+}
+
+/// Sphinx running one of the `*/program` guests.
+///
+/// By default prove makes the core proof through the SDK. With `compress`
+/// it goes on through the recursion stages that run locally, proving and
+/// verifying `core`, `compress`, `shrink` and `wrap_bn254` as separately
+/// timed stages; the Groth16 and PLONK wrappers need containers and are left
+/// out.
+pub struct Sphinx {
+    program: Program,
+    elf: &'static [u8],
+    /// Shard size and batching of the core prover, also recorded in the
+    /// report.
+    opts: SphinxCoreOpts,
+    compress: bool,
+    cycles: Option<u64>,
+    stages: Vec<(&'static str, Measurement)>,
+}
+
+impl Sphinx {
+    /// Proves with [`opts_from_env`], compressing if `SPHINX_COMPRESS` is
+    /// true.
+    pub fn new(program: Program, elf: &'static [u8]) -> Self {
+        Self::with_opts(
+            program,
+            elf,
+            opts_from_env(),
+            env_or("SPHINX_COMPRESS", false),
+        )
+    }
+
+    pub fn with_opts(
+        program: Program,
+        elf: &'static [u8],
+        opts: SphinxCoreOpts,
+        compress: bool,
+    ) -> Self {
+        Self {
+            program,
+            elf,
+            opts,
+            compress,
+            cycles: None,
+            stages: Vec::new(),
+        }
+    }
+
+    fn stdin(&self, input: &Input) -> SphinxStdin {
+        let mut stdin = SphinxStdin::new();
+        match self.program {
+            Program::Fib | Program::FastFib => stdin.write(&input.expect_n()),
+            Program::Sum => stdin.write(&(0..input.expect_n()).collect::<Vec<u64>>()),
+            Program::Lcs | Program::Lcs2 => stdin.write(&input.expect_args()),
+        }
+        stdin
+    }
+
+    /// Every guest commits its input followed by its output.
+    fn read_output(&self, public_values: &mut SphinxPublicValues, input: &Input) -> Output {
+        match self.program {
+            Program::Fib | Program::FastFib => {
+                assert_eq!(public_values.read::<u64>(), input.expect_n());
+                public_values.read::<u64>().into()
+            }
+            Program::Sum => {
+                let nums = public_values.read::<Vec<u64>>();
+                assert_eq!(nums.len() as u64, input.expect_n());
+                public_values.read::<u64>().into()
+            }
+            Program::Lcs | Program::Lcs2 => {
+                assert_eq!(
+                    public_values.read::<(String, String)>(),
+                    input.expect_args()
+                );
+                public_values.read::<String>().into()
+            }
+        }
+    }
+
+    /// Proves stage by stage with the SDK's prover.
+    fn prove_compressed(&mut self, setup: &SphinxSetup, input: &Input) -> (SphinxProof, Output) {
+        let prover = setup.client.prover.sphinx_prover();
+        let opts = SphinxProverOpts {
+            core_opts: self.opts,
+            ..Default::default()
+        };
+        let stdin = self.stdin(input);
+
+        let it = PhaseMeter::start();
+        let core = prover
+            .prove_core(&setup.pk, &stdin, opts, SphinxContext::default())
+            .unwrap();
+        self.stages.push(("core", it.stop()));
+
+        let it = PhaseMeter::start();
+        let compress = prover
+            .compress(&setup.vk, core.clone(), Vec::new(), opts)
+            .unwrap();
+        self.stages.push(("compress", it.stop()));
+
+        let it = PhaseMeter::start();
+        let shrink = prover.shrink(compress.clone(), opts).unwrap();
+        self.stages.push(("shrink", it.stop()));
+
+        let it = PhaseMeter::start();
+        let wrap_bn254 = prover.wrap_bn254(shrink.clone(), opts).unwrap();
+        self.stages.push(("wrap_bn254", it.stop()));
+
+        let output = self.read_output(&mut core.public_values.clone(), input);
+        let proof = SphinxProof::Compressed {
+            core,
+            compress,
+            shrink,
+            wrap_bn254,
+        };
+        (proof, output)
+    }
+}
+
+/// The SDK's core proof, or the proof of every stage when compressing.
+#[derive(Clone)]
+pub enum SphinxProof {
+    Core(SphinxProofWithPublicValues),
+    Compressed {
+        core: SphinxCoreProof,
+        compress: SphinxReduceProof<InnerSC>,
+        shrink: SphinxReduceProof<InnerSC>,
+        wrap_bn254: SphinxReduceProof<OuterSC>,
+    },
+}
+
+pub struct SphinxSetup {
+    client: ProverClient,
+    pk: SphinxProvingKey,
+    vk: SphinxVerifyingKey,
+}
+
+impl ZkvmBackend for Sphinx {
+    const BACKEND: Backend = Backend::Sphinx;
+
+    type Setup = SphinxSetup;
+    type Proof = SphinxProof;
+
+    fn program(&self) -> Option<Program> {
+        Some(self.program)
+    }
+
+    fn setup(&mut self, _input: &Input) -> SphinxSetup {
+        let client = ProverClient::new();
+        let (pk, vk) = client.setup(self.elf);
+        SphinxSetup { client, pk, vk }
+    }
+
+    fn execute(&mut self, setup: &mut SphinxSetup, input: &Input) -> Output {
+        let (mut public_values, report) = setup
+            .client
+            .execute(self.elf, self.stdin(input))
+            .run()
+            .unwrap();
+        self.cycles = Some(report.total_instruction_count());
+        self.read_output(&mut public_values, input)
+    }
+
+    fn prove(&mut self, setup: &mut SphinxSetup, input: &Input) -> (Self::Proof, Output) {
+        if self.compress {
+            return self.prove_compressed(setup, input);
+        }
+        let proof = setup
+            .client
+            .prove(&setup.pk, self.stdin(input))
+            .shard_size(self.opts.shard_size)
+            .shard_batch_size(self.opts.shard_batch_size)
+            .shard_chunking_multiplier(self.opts.shard_chunking_multiplier)
+            .reconstruct_commitments(self.opts.reconstruct_commitments)
+            .run()
+            .unwrap();
+        let output = self.read_output(&mut proof.public_values.clone(), input);
+        (SphinxProof::Core(proof), output)
+    }
+
+    fn verify(&mut self, setup: &SphinxSetup, proof: Self::Proof) {
+        match proof {
+            SphinxProof::Core(proof) => setup
+                .client
+                .verify(&proof, &setup.vk)
+                .expect("verification failed"),
+            SphinxProof::Compressed {
+                core,
+                compress,
+                shrink,
+                wrap_bn254,
+            } => {
+                let prover = setup.client.prover.sphinx_prover();
+                let it = PhaseMeter::start();
+                prover
+                    .verify(&core.proof, &setup.vk)
+                    .expect("core verification failed");
+                self.stages.push(("core", it.stop()));
+
+                let it = PhaseMeter::start();
+                prover
+                    .verify_compressed(&compress, &setup.vk)
+                    .expect("compress verification failed");
+                self.stages.push(("compress", it.stop()));
+
+                let it = PhaseMeter::start();
+                prover
+                    .verify_shrink(&shrink, &setup.vk)
+                    .expect("shrink verification failed");
+                self.stages.push(("shrink", it.stop()));
+
+                let it = PhaseMeter::start();
+                prover
+                    .verify_wrap_bn254(&wrap_bn254, &setup.vk)
+                    .expect("wrap_bn254 verification failed");
+                self.stages.push(("wrap_bn254", it.stop()));
+            }
+        }
+    }
+
+    /// The compressed proof is the one reported as `proof_bytes`, as with
+    /// SP1.
+    fn proof_sizes(&self, setup: &SphinxSetup, proof: &Self::Proof) -> ProofSizes {
+        let vk_bytes = Some(bincode::serialized_size(&setup.vk).unwrap() as usize);
+        match proof {
+            SphinxProof::Core(proof) => ProofSizes {
+                proof_bytes: bincode::serialized_size(proof).unwrap() as usize,
+                vk_bytes,
+                stages: Vec::new(),
+            },
+            SphinxProof::Compressed {
+                core,
+                compress,
+                shrink,
+                wrap_bn254,
+            } => ProofSizes {
+                proof_bytes: bincode::serialized_size(compress).unwrap() as usize,
+                vk_bytes,
+                stages: vec![
+                    ("core", bincode::serialized_size(core).unwrap() as usize),
+                    (
+                        "compress",
+                        bincode::serialized_size(compress).unwrap() as usize,
+                    ),
+                    ("shrink", bincode::serialized_size(shrink).unwrap() as usize),
+                    (
+                        "wrap_bn254",
+                        bincode::serialized_size(wrap_bn254).unwrap() as usize,
+                    ),
+                ],
+            },
+        }
+    }
+
+    fn take_stages(&mut self) -> Vec<(&'static str, Measurement)> {
+        std::mem::take(&mut self.stages)
+    }
+
+    fn annotate(&self, report: &mut Report) {
+        report.opts = CoreOpts {
+            shard_size: Some(self.opts.shard_size),
+            shard_batch_size: Some(self.opts.shard_batch_size),
+            shard_chunking_multiplier: Some(self.opts.shard_chunking_multiplier),
+            reconstruct_commitments: Some(self.opts.reconstruct_commitments),
+            segment_limit_po2: None,
+        };
+        report.metrics.cycles = self.cycles;
+        report.elf_sha256 = Some(hex::encode(Sha256::digest(self.elf)));
+    }
+}
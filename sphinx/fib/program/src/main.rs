@@ -6,15 +6,7 @@ pub fn main() {
 
     sphinx_zkvm::io::commit(&n);
 
-    // Compute the n'th fibonacci number, using normal Rust code.
-    let mut a: u64 = 0;
-    let mut b: u64 = 1;
-    for _ in 0..n {
-        // Naturally overflow at 64 bits
-        let c: u64 = a.wrapping_add(b);
-        a = b;
-        b = c;
-    }
+    let result = bench_algos::fib(n);
 
-    sphinx_zkvm::io::commit(&a);
+    sphinx_zkvm::io::commit(&result);
 }
@@ -0,0 +1,7 @@
+use bench_common::Program;
+use sphinx_backend::Sphinx;
+
+const ELF: &[u8] = include_bytes!("../../program/elf/riscv32im-succinct-zkvm-elf");
+
+// `cargo bench --bench lcs2`
+bench_common::criterion_phases!(Sphinx::new(Program::Lcs2, ELF), Clone::clone);
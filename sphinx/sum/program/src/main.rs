@@ -4,7 +4,7 @@ sphinx_zkvm::entrypoint!(main);
 pub fn main() {
     let input = sphinx_zkvm::io::read::<Vec<u64>>();
 
-    let output: u64 = input.iter().sum();
+    let output: u64 = bench_algos::sum(&input);
 
     sphinx_zkvm::io::commit(&input);
     sphinx_zkvm::io::commit(&output);
@@ -0,0 +1,6 @@
+fn main() {
+    sphinx_helper::build_program("../program");
+    // The guest's code is in bench-algos, which the helper does not watch.
+    println!("cargo:rerun-if-changed=../../../bench-algos/src");
+    println!("cargo:rerun-if-changed=../../../bench-algos/Cargo.toml");
+}
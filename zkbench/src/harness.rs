@@ -0,0 +1,134 @@
+use bench_common::{Backend, Input, Program, Report, RunConfig};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Where the harness binary for one (program, backend) pair lives.
+#[derive(Clone, Debug)]
+pub struct Harness {
+    pub program: Program,
+    pub backend: Backend,
+    /// Cargo workspace root; its `rust-toolchain.toml` picks the toolchain.
+    pub workspace: PathBuf,
+    pub package: String,
+}
+
+impl Harness {
+    /// Returns `None` if the backend has no harness for the program.
+    pub fn locate(root: &Path, program: Program, backend: Backend) -> Option<Self> {
+        let p = program.name();
+        let (workspace, package_dir, package) = match backend {
+            Backend::Jolt => {
+                let ws = root.join("jolt").join(p);
+                (ws.clone(), ws, format!("{p}-jolt"))
+            }
+            Backend::Lurk => {
+                let ws = root.join("lurk");
+                (ws.clone(), ws.join(p), format!("{p}-lurk"))
+            }
+            Backend::Risc0 => {
+                let ws = root.join("risc0").join(p);
+                (ws.clone(), ws.join("host"), "host".to_string())
+            }
+            Backend::Sp1 => {
+                let ws = root.join("sp1");
+                (
+                    ws.clone(),
+                    ws.join(p).join("script"),
+                    format!("{p}-sp1-script"),
+                )
+            }
+            Backend::Sphinx => {
+                let ws = root.join("sphinx");
+                (
+                    ws.clone(),
+                    ws.join(p).join("script"),
+                    format!("{p}-sphinx-script"),
+                )
+            }
+        };
+        package_dir.join("Cargo.toml").exists().then_some(Self {
+            program,
+            backend,
+            workspace,
+            package,
+        })
+    }
+
+    pub fn name(&self) -> String {
+        format!("{}-{}", self.program, self.backend)
+    }
+
+    fn binary(&self) -> PathBuf {
+        self.workspace
+            .join("target")
+            .join("release")
+            .join(&self.package)
+    }
+
+    /// Runs the harness on `input` (or its default input), with the extra
+    /// environment variables `env`, and collects the reports it prints.
+    pub fn run(
+        &self,
+        input: Option<&Input>,
+        config: &RunConfig,
+        env: &[(String, String)],
+    ) -> Result<Vec<Report>, String> {
+        let mut cmd = Command::new(self.binary());
+        cmd.current_dir(&self.workspace)
+            .envs(config.env())
+            .envs(env.iter().cloned())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit());
+        if let Some(input) = input {
+            cmd.envs(self.program.input_env(input));
+        }
+        let output = cmd
+            .output()
+            .map_err(|e| format!("could not start {}: {e}", self.binary().display()))?;
+        if !output.status.success() {
+            return Err(format!("{} exited with {}", self.name(), output.status));
+        }
+        // Loggers share stdout with the report, so skip anything that is not one.
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+/// A `cargo` invocation inside `dir` that honours the directory's
+/// `rust-toolchain.toml`, even when zkbench itself runs under `cargo run`.
+fn cargo(dir: &Path) -> Command {
+    let mut cmd = Command::new("cargo");
+    cmd.current_dir(dir)
+        .env_remove("RUSTUP_TOOLCHAIN")
+        .env_remove("RUSTC")
+        .env_remove("CARGO_TARGET_DIR");
+    cmd
+}
+
+/// Builds every harness in release mode, one `cargo build` per workspace.
+pub fn build(harnesses: &[Harness]) -> Result<(), String> {
+    let mut workspaces: Vec<(&Path, Vec<&str>)> = Vec::new();
+    for h in harnesses {
+        match workspaces.iter_mut().find(|(ws, _)| *ws == h.workspace) {
+            Some((_, packages)) => packages.push(&h.package),
+            None => workspaces.push((&h.workspace, vec![&h.package])),
+        }
+    }
+    for (workspace, packages) in workspaces {
+        eprintln!("building {}", workspace.display());
+        let mut cmd = cargo(workspace);
+        cmd.args(["build", "--release"]);
+        for package in packages {
+            cmd.args(["-p", package]);
+        }
+        let status = cmd
+            .status()
+            .map_err(|e| format!("could not run cargo: {e}"))?;
+        if !status.success() {
+            return Err(format!("building {} failed", workspace.display()));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,569 @@
+use bench_common::{Backend, Input, Mode, Program, Report, RunConfig};
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+mod harness;
+mod suite;
+
+use harness::Harness;
+use suite::Suite;
+
+#[derive(Parser)]
+#[command(about = "Builds and runs the zkVM benchmark harnesses")]
+struct Cli {
+    /// Repository root holding the jolt/, lurk/, risc0/, sp1/ and sphinx/ directories
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    root: PathBuf,
+
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Run every selected (program, backend, input) combination
+    Run(RunArgs),
+    /// Run the combinations described by a suite manifest
+    Suite(SuiteArgs),
+    /// Run one program on one input on every backend and check every output
+    /// against the reference implementation
+    Check(CheckArgs),
+    /// Prove one program on one input over a grid of core options (Sphinx, Lurk)
+    Sweep(SweepArgs),
+}
+
+/// Reports arguments that parse but do not fit together the way clap reports
+/// its own errors, exiting with status 2 rather than panicking.
+fn usage_error(kind: ErrorKind, message: impl std::fmt::Display) -> ! {
+    Cli::command().error(kind, message).exit()
+}
+
+#[derive(Args)]
+struct OutputArgs {
+    /// JSON-lines file the collected reports are written to
+    #[arg(short, long, default_value = "results.jsonl")]
+    output: PathBuf,
+
+    /// Run previously built harnesses without rebuilding them
+    #[arg(long)]
+    no_build: bool,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Programs to run [default: all]
+    #[arg(short, long, value_delimiter = ',')]
+    programs: Vec<Program>,
+
+    /// Backends to run [default: all]
+    #[arg(short, long, value_delimiter = ',')]
+    backends: Vec<Backend>,
+
+    /// Input for fib, fastfib and sum; repeat for several runs [default: the harness default]
+    #[arg(long = "n")]
+    ns: Vec<u64>,
+
+    /// String pair for lcs and lcs2; repeat for several runs [default: the harness default]
+    #[arg(long = "args", num_args = 2, value_names = ["A", "B"])]
+    args: Vec<String>,
+
+    /// Measured iterations of each combination
+    #[arg(long, default_value_t = 1)]
+    repetitions: usize,
+
+    /// Unmeasured iterations run before the measured ones
+    #[arg(long, default_value_t = 0)]
+    warmup: usize,
+
+    /// Stop after execution: report execution time, cycles and outputs without proving
+    #[arg(long)]
+    execute_only: bool,
+
+    #[command(flatten)]
+    out: OutputArgs,
+}
+
+#[derive(Args)]
+struct SuiteArgs {
+    /// Suite manifest, e.g. suites/nightly.toml
+    manifest: PathBuf,
+
+    #[command(flatten)]
+    out: OutputArgs,
+}
+
+/// The single input of `check` and `sweep`.
+#[derive(Args)]
+struct InputArgs {
+    /// Input for fib, fastfib and sum [default: small for check, the harness default for sweep]
+    #[arg(long, conflicts_with = "args")]
+    n: Option<u64>,
+
+    /// String pair for lcs and lcs2 [default: small for check, the harness default for sweep]
+    #[arg(long, num_args = 2, value_names = ["A", "B"])]
+    args: Option<Vec<String>>,
+}
+
+impl InputArgs {
+    fn input(&self, program: Program, default: fn(Program) -> Input) -> Input {
+        match (&self.n, &self.args) {
+            (Some(n), None) if program.takes_n() => Input::N(*n),
+            (None, Some(ab)) if !program.takes_n() => Input::Args(ab[0].clone(), ab[1].clone()),
+            (None, None) => default(program),
+            _ => usage_error(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "{program} takes {}",
+                    if program.takes_n() { "--n" } else { "--args" }
+                ),
+            ),
+        }
+    }
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Program to run
+    program: Program,
+
+    /// Backends to compare [default: all]
+    #[arg(short, long, value_delimiter = ',')]
+    backends: Vec<Backend>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Check proven outputs instead of only executing the guests
+    #[arg(long)]
+    prove: bool,
+
+    #[command(flatten)]
+    out: OutputArgs,
+}
+
+#[derive(Args)]
+struct SweepArgs {
+    /// Program to prove
+    program: Program,
+
+    /// Backends to sweep, among sphinx and lurk [default: both]
+    #[arg(short, long, value_delimiter = ',')]
+    backends: Vec<Backend>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Shard sizes to try [default: the backend default]
+    #[arg(long, value_delimiter = ',')]
+    shard_size: Vec<usize>,
+
+    /// Shard batch sizes to try [default: the backend default]
+    #[arg(long, value_delimiter = ',')]
+    shard_batch_size: Vec<usize>,
+
+    /// Shard chunking multipliers to try [default: the backend default]
+    #[arg(long, value_delimiter = ',')]
+    shard_chunking_multiplier: Vec<usize>,
+
+    /// Measured iterations of each setting
+    #[arg(long, default_value_t = 1)]
+    repetitions: usize,
+
+    /// Unmeasured iterations run before the measured ones
+    #[arg(long, default_value_t = 0)]
+    warmup: usize,
+
+    #[command(flatten)]
+    out: OutputArgs,
+}
+
+impl SweepArgs {
+    /// Every combination of the swept options, as the environment variables
+    /// the Sphinx and Lurk harnesses read them from. An option with no values
+    /// is left at the backend default.
+    fn grid(&self) -> Vec<Vec<(String, String)>> {
+        let axes = [
+            ("SHARD_SIZE", &self.shard_size),
+            ("SHARD_BATCH_SIZE", &self.shard_batch_size),
+            ("SHARD_CHUNKING_MULTIPLIER", &self.shard_chunking_multiplier),
+        ];
+        let mut grid = vec![Vec::new()];
+        for (var, values) in axes {
+            if values.is_empty() {
+                continue;
+            }
+            grid = grid
+                .into_iter()
+                .flat_map(|env: Vec<(String, String)>| {
+                    values.iter().map(move |v| {
+                        let mut env = env.clone();
+                        env.push((var.to_string(), v.to_string()));
+                        env
+                    })
+                })
+                .collect();
+        }
+        grid
+    }
+}
+
+impl RunArgs {
+    fn inputs(&self, program: Program) -> Vec<Option<Input>> {
+        let inputs: Vec<_> = if program.takes_n() {
+            self.ns.iter().map(|&n| Some(Input::N(n))).collect()
+        } else {
+            self.args
+                .chunks(2)
+                .map(|ab| Some(Input::Args(ab[0].clone(), ab[1].clone())))
+                .collect()
+        };
+        if inputs.is_empty() {
+            vec![None]
+        } else {
+            inputs
+        }
+    }
+}
+
+/// One harness run; `None` runs the harness on its default input.
+struct Job {
+    harness: Harness,
+    input: Option<Input>,
+    /// Extra environment variables of the run.
+    env: Vec<(String, String)>,
+}
+
+fn or_all<T: Copy>(selected: &[T], all: &[T]) -> Vec<T> {
+    if selected.is_empty() {
+        all.to_vec()
+    } else {
+        selected.to_vec()
+    }
+}
+
+fn locate(root: &Path, program: Program, backend: Backend) -> Option<Harness> {
+    let harness = Harness::locate(root, program, backend);
+    if harness.is_none() {
+        eprintln!("skipping {program}-{backend}: no harness");
+    }
+    harness
+}
+
+fn write_reports(path: &Path, reports: &[Report]) {
+    let mut out = BufWriter::new(File::create(path).expect("could not create output file"));
+    for report in reports {
+        writeln!(out, "{}", serde_json::to_string(report).unwrap()).unwrap();
+    }
+}
+
+/// Builds the harnesses of `jobs`, runs each job with `config` and writes
+/// every report to the output file.
+fn run_jobs(jobs: &[Job], config: &RunConfig, out: &OutputArgs) -> Vec<Report> {
+    if !out.no_build {
+        let mut harnesses: Vec<Harness> = Vec::new();
+        for job in jobs {
+            if !harnesses.iter().any(|h| h.name() == job.harness.name()) {
+                harnesses.push(job.harness.clone());
+            }
+        }
+        harness::build(&harnesses).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    let mut reports = Vec::new();
+    let mut failures = 0;
+    for Job {
+        harness,
+        input,
+        env,
+    } in jobs
+    {
+        match input {
+            Some(input) => eprintln!("running {}({input})", harness.name()),
+            None => eprintln!("running {} on its default input", harness.name()),
+        }
+        if !env.is_empty() {
+            let env: Vec<_> = env.iter().map(|(k, v)| format!("{k}={v}")).collect();
+            eprintln!("  with {}", env.join(" "));
+        }
+        match harness.run(input.as_ref(), config, env) {
+            Ok(mut rs) => reports.append(&mut rs),
+            Err(e) => {
+                eprintln!("{e}");
+                failures += 1;
+            }
+        }
+    }
+
+    write_reports(&out.output, &reports);
+    let wrong = reports
+        .iter()
+        .filter(|r| r.check.as_ref().is_some_and(|c| !c.passed))
+        .count();
+    eprintln!(
+        "wrote {} reports to {} ({failures} failed runs, {wrong} wrong outputs)",
+        reports.len(),
+        out.output.display()
+    );
+    reports
+}
+
+fn run(root: &Path, args: RunArgs) {
+    let mut jobs = Vec::new();
+    for program in or_all(&args.programs, &Program::ALL) {
+        for backend in or_all(&args.backends, &Backend::ALL) {
+            if let Some(harness) = locate(root, program, backend) {
+                for input in args.inputs(program) {
+                    jobs.push(Job {
+                        harness: harness.clone(),
+                        input,
+                        env: Vec::new(),
+                    });
+                }
+            }
+        }
+    }
+    let config = RunConfig {
+        repetitions: args.repetitions,
+        warmup: args.warmup,
+        mode: if args.execute_only {
+            Mode::Execute
+        } else {
+            Mode::Full
+        },
+    };
+    run_jobs(&jobs, &config, &args.out);
+}
+
+fn suite(root: &Path, args: SuiteArgs) {
+    let suite =
+        Suite::load(&args.manifest).unwrap_or_else(|e| usage_error(ErrorKind::InvalidValue, e));
+    if !suite.description.is_empty() {
+        eprintln!("{}", suite.description);
+    }
+    let jobs: Vec<_> = suite
+        .matrix()
+        .unwrap_or_else(|e| usage_error(ErrorKind::InvalidValue, e))
+        .into_iter()
+        .filter_map(|(program, backend, input)| {
+            let harness = locate(root, program, backend)?;
+            Some(Job {
+                harness,
+                input: Some(input),
+                env: Vec::new(),
+            })
+        })
+        .collect();
+    run_jobs(&jobs, &suite.config(), &args.out);
+}
+
+/// Inputs every backend gets through quickly, Lurk's exponential fib and lcs
+/// included. The lcs pair has several longest common subsequences.
+fn check_input(program: Program) -> Input {
+    match program {
+        Program::Fib => Input::N(20),
+        Program::FastFib | Program::Sum => Input::N(1000),
+        Program::Lcs | Program::Lcs2 => Input::Args("ABCBDAB".into(), "BDCABA".into()),
+    }
+}
+
+/// Each output is judged by the reference check of its own backend, as Lurk
+/// computes numbers in the BabyBear field and its lcs breaks ties its own way.
+/// The other backends all run the `bench_algos` code, so beyond that any of
+/// them whose output differs from the one most of them agree on has diverged,
+/// even if its output is a valid answer.
+fn check(root: &Path, args: CheckArgs) {
+    let input = args.input.input(args.program, check_input);
+    let jobs: Vec<_> = or_all(&args.backends, &Backend::ALL)
+        .into_iter()
+        .filter_map(|backend| {
+            let harness = locate(root, args.program, backend)?;
+            Some(Job {
+                harness,
+                input: Some(input.clone()),
+                env: Vec::new(),
+            })
+        })
+        .collect();
+    let config = RunConfig {
+        mode: if args.prove {
+            Mode::Full
+        } else {
+            Mode::Execute
+        },
+        ..RunConfig::default()
+    };
+    let reports = run_jobs(&jobs, &config, &args.out);
+
+    let shared: Vec<_> = reports
+        .iter()
+        .filter(|r| r.backend != Backend::Lurk)
+        .collect();
+    let agreed = shared
+        .iter()
+        .filter_map(|r| r.output.as_ref())
+        .max_by_key(|&o| {
+            shared
+                .iter()
+                .filter(|r| r.output.as_ref() == Some(o))
+                .count()
+        });
+    let diverges = |r: &Report| r.backend != Backend::Lurk && r.output.as_ref() != agreed;
+
+    println!("{}({input})", args.program);
+    for report in &reports {
+        let output = report
+            .output
+            .as_ref()
+            .map_or("-".to_string(), |o| o.to_string());
+        let reference = match &report.check {
+            Some(c) if c.passed => "ok".to_string(),
+            Some(c) => format!("wrong, expected {}", c.expected),
+            None => "-".to_string(),
+        };
+        let agreement = if diverges(report) {
+            format!(
+                "  [others: {}]",
+                agreed.map_or("-".to_string(), |o| o.to_string())
+            )
+        } else {
+            String::new()
+        };
+        println!(
+            "  {:<8} {output}  [reference: {reference}]{agreement}",
+            report.backend
+        );
+    }
+    for job in &jobs {
+        if !reports.iter().any(|r| r.backend == job.harness.backend) {
+            println!("  {:<8} failed", job.harness.backend);
+        }
+    }
+
+    let wrong = reports
+        .iter()
+        .any(|r| !r.check.as_ref().is_some_and(|c| c.passed) || diverges(r));
+    if wrong || reports.len() < jobs.len() {
+        std::process::exit(1);
+    }
+}
+
+/// Prints one row per setting, with the options as the backend reports having
+/// used them rather than as requested.
+fn sweep(root: &Path, args: SweepArgs) {
+    let backends = or_all(&args.backends, &[Backend::Sphinx, Backend::Lurk]);
+    if let Some(b) = backends
+        .iter()
+        .find(|b| !matches!(b, Backend::Sphinx | Backend::Lurk))
+    {
+        usage_error(
+            ErrorKind::InvalidValue,
+            format!("{b} has no sharding options to sweep; use sphinx or lurk"),
+        );
+    }
+    let input = args.input.input(args.program, Program::default_input);
+    let grid = args.grid();
+    let mut jobs = Vec::new();
+    for backend in backends {
+        if let Some(harness) = locate(root, args.program, backend) {
+            for env in &grid {
+                jobs.push(Job {
+                    harness: harness.clone(),
+                    input: Some(input.clone()),
+                    env: env.clone(),
+                });
+            }
+        }
+    }
+    let config = RunConfig {
+        repetitions: args.repetitions,
+        warmup: args.warmup,
+        mode: Mode::Full,
+    };
+    let reports = run_jobs(&jobs, &config, &args.out);
+
+    let opt = |o: Option<usize>| o.map_or("-".to_string(), |o| o.to_string());
+    println!("{}({input})", args.program);
+    println!(
+        "  {:<8} {:>10} {:>11} {:>8} {:>10} {:>13} {:>12}",
+        "backend",
+        "shard_size",
+        "batch_size",
+        "chunking",
+        "prove (s)",
+        "peak RSS (MB)",
+        "proof (B)"
+    );
+    for report in &reports {
+        let prove = report.phases.get("prove");
+        println!(
+            "  {:<8} {:>10} {:>11} {:>8} {:>10} {:>13} {:>12}",
+            report.backend,
+            opt(report.opts.shard_size),
+            opt(report.opts.shard_batch_size),
+            opt(report.opts.shard_chunking_multiplier),
+            prove.map_or("-".to_string(), |p| format!("{:.3}", p.summary.mean)),
+            prove
+                .and_then(|p| p.peak_rss_bytes)
+                .map_or("-".to_string(), |b| format!("{:.1}", b as f64 / 1e6)),
+            opt(report.proof_bytes),
+        );
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Cmd::Run(args) => run(&cli.root, args),
+        Cmd::Suite(args) => suite(&cli.root, args),
+        Cmd::Check(args) => check(&cli.root, args),
+        Cmd::Sweep(args) => sweep(&cli.root, args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(args: &[&str]) -> Vec<Vec<(String, String)>> {
+        let cli = Cli::try_parse_from(["zkbench", "sweep", "fib"].iter().chain(args)).unwrap();
+        match cli.command {
+            Cmd::Sweep(args) => args.grid(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn empty_grid_is_one_default_setting() {
+        assert_eq!(grid(&[]), vec![Vec::new()]);
+    }
+
+    #[test]
+    fn grid_is_the_product_of_the_given_axes() {
+        let g = grid(&["--shard-size", "1,2", "--shard-chunking-multiplier", "3,4"]);
+        assert_eq!(
+            g,
+            vec![
+                env(&[("SHARD_SIZE", "1"), ("SHARD_CHUNKING_MULTIPLIER", "3")]),
+                env(&[("SHARD_SIZE", "1"), ("SHARD_CHUNKING_MULTIPLIER", "4")]),
+                env(&[("SHARD_SIZE", "2"), ("SHARD_CHUNKING_MULTIPLIER", "3")]),
+                env(&[("SHARD_SIZE", "2"), ("SHARD_CHUNKING_MULTIPLIER", "4")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_with_one_axis() {
+        assert_eq!(
+            grid(&["--shard-batch-size", "8"]),
+            vec![env(&[("SHARD_BATCH_SIZE", "8")])]
+        );
+    }
+}
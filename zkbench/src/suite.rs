@@ -0,0 +1,263 @@
+use bench_common::{Backend, Input, Mode, Program, RunConfig};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// A checked-in description of benchmark runs, such as `suites/nightly.toml`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suite {
+    #[serde(default)]
+    pub description: String,
+    /// Measured iterations of every combination.
+    #[serde(default = "default_repetitions")]
+    pub repetitions: usize,
+    /// Unmeasured iterations run before the measured ones.
+    #[serde(default)]
+    pub warmup: usize,
+    /// `execute` skips proving and verification.
+    #[serde(default)]
+    pub mode: Mode,
+    /// Named input sets, each giving one input per program.
+    pub inputs: BTreeMap<String, BTreeMap<Program, InputValue>>,
+    #[serde(rename = "run")]
+    pub runs: Vec<Run>,
+}
+
+fn default_repetitions() -> usize {
+    1
+}
+
+/// An input as written in a manifest: a number, a number in a string (TOML
+/// integers stop at `i64::MAX`), or a pair of strings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum InputValue {
+    N(u64),
+    Decimal(String),
+    Args(String, String),
+}
+
+impl InputValue {
+    fn to_input(&self) -> Result<Input, String> {
+        match self {
+            InputValue::N(n) => Ok(Input::N(*n)),
+            InputValue::Decimal(s) => s
+                .parse()
+                .map(Input::N)
+                .map_err(|_| format!("{s:?} is not a u64")),
+            InputValue::Args(a, b) => Ok(Input::Args(a.clone(), b.clone())),
+        }
+    }
+}
+
+/// Runs `programs` on `backends` with each of the named input sets.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Run {
+    /// Defaults to every program the input set defines.
+    #[serde(default)]
+    pub programs: Vec<Program>,
+    /// Defaults to every backend.
+    #[serde(default)]
+    pub backends: Vec<Backend>,
+    pub inputs: Vec<String>,
+}
+
+impl Suite {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        toml::from_str(&text).map_err(|e| format!("invalid suite {}: {e}", path.display()))
+    }
+
+    pub fn config(&self) -> RunConfig {
+        RunConfig {
+            repetitions: self.repetitions,
+            warmup: self.warmup,
+            mode: self.mode,
+        }
+    }
+
+    /// Expands the runs into `(program, backend, input)` combinations.
+    pub fn matrix(&self) -> Result<Vec<(Program, Backend, Input)>, String> {
+        let mut matrix = Vec::new();
+        for run in &self.runs {
+            let backends = if run.backends.is_empty() {
+                Backend::ALL.to_vec()
+            } else {
+                run.backends.clone()
+            };
+            for name in &run.inputs {
+                let set = self
+                    .inputs
+                    .get(name)
+                    .ok_or_else(|| format!("unknown input set {name:?}"))?;
+                let programs = if run.programs.is_empty() {
+                    set.keys().copied().collect()
+                } else {
+                    run.programs.clone()
+                };
+                for program in programs {
+                    let value = set
+                        .get(&program)
+                        .ok_or_else(|| format!("input set {name:?} has no input for {program}"))?;
+                    let input = value
+                        .to_input()
+                        .map_err(|e| format!("{name}.{program}: {e}"))?;
+                    if program.takes_n() != matches!(input, Input::N(_)) {
+                        return Err(format!("{name}.{program}: wrong kind of input"));
+                    }
+                    for &backend in &backends {
+                        matrix.push((program, backend, input.clone()));
+                    }
+                }
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(text: &str) -> Result<Vec<(Program, Backend, Input)>, String> {
+        toml::from_str::<Suite>(text).unwrap().matrix()
+    }
+
+    #[test]
+    fn decimal_strings_go_above_i64_max() {
+        let m = matrix(
+            r#"
+            inputs.big.fastfib = "18446744073709551614"
+            [[run]]
+            backends = ["sp1"]
+            inputs = ["big"]
+            "#,
+        );
+        assert_eq!(
+            m.unwrap(),
+            vec![(Program::FastFib, Backend::Sp1, Input::N(u64::MAX - 1))]
+        );
+    }
+
+    #[test]
+    fn decimal_strings_must_be_u64s() {
+        let m = matrix(
+            r#"
+            inputs.big.fastfib = "18446744073709551616"
+            [[run]]
+            inputs = ["big"]
+            "#,
+        );
+        assert_eq!(
+            m.unwrap_err(),
+            r#"big.fastfib: "18446744073709551616" is not a u64"#
+        );
+    }
+
+    #[test]
+    fn inputs_must_be_of_the_programs_kind() {
+        let m = matrix(
+            r#"
+            inputs.s.fib = ["a", "b"]
+            [[run]]
+            inputs = ["s"]
+            "#,
+        );
+        assert_eq!(m.unwrap_err(), "s.fib: wrong kind of input");
+
+        let m = matrix(
+            r#"
+            inputs.s.lcs = 5
+            [[run]]
+            inputs = ["s"]
+            "#,
+        );
+        assert_eq!(m.unwrap_err(), "s.lcs: wrong kind of input");
+    }
+
+    #[test]
+    fn input_sets_must_exist_and_cover_the_programs() {
+        let m = matrix(
+            r#"
+            inputs.s.fib = 5
+            [[run]]
+            inputs = ["t"]
+            "#,
+        );
+        assert_eq!(m.unwrap_err(), r#"unknown input set "t""#);
+
+        let m = matrix(
+            r#"
+            inputs.s.fib = 5
+            [[run]]
+            programs = ["sum"]
+            inputs = ["s"]
+            "#,
+        );
+        assert_eq!(m.unwrap_err(), r#"input set "s" has no input for sum"#);
+    }
+
+    #[test]
+    fn runs_default_to_every_program_of_the_set_and_every_backend() {
+        let m = matrix(
+            r#"
+            inputs.s.sum = 10
+            inputs.s.fib = 5
+            [[run]]
+            inputs = ["s"]
+            "#,
+        )
+        .unwrap();
+        let expected: Vec<_> = [(Program::Fib, 5), (Program::Sum, 10)]
+            .into_iter()
+            .flat_map(|(p, n)| Backend::ALL.map(|b| (p, b, Input::N(n))))
+            .collect();
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn runs_expand_in_order() {
+        let m = matrix(
+            r#"
+            [inputs.small]
+            fib = 5
+            lcs = ["ab", "b"]
+            [inputs.large]
+            fib = 50
+            lcs = ["abc", "bc"]
+            [[run]]
+            programs = ["lcs", "fib"]
+            backends = ["risc0", "lurk"]
+            inputs = ["small", "large"]
+            "#,
+        )
+        .unwrap();
+        let lcs = |a: &str, b: &str| Input::Args(a.into(), b.into());
+        assert_eq!(
+            m,
+            vec![
+                (Program::Lcs, Backend::Risc0, lcs("ab", "b")),
+                (Program::Lcs, Backend::Lurk, lcs("ab", "b")),
+                (Program::Fib, Backend::Risc0, Input::N(5)),
+                (Program::Fib, Backend::Lurk, Input::N(5)),
+                (Program::Lcs, Backend::Risc0, lcs("abc", "bc")),
+                (Program::Lcs, Backend::Lurk, lcs("abc", "bc")),
+                (Program::Fib, Backend::Risc0, Input::N(50)),
+                (Program::Fib, Backend::Lurk, Input::N(50)),
+            ]
+        );
+    }
+
+    #[test]
+    fn checked_in_suites_expand() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../suites");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let suite = Suite::load(&path).unwrap();
+            assert!(!suite.matrix().unwrap().is_empty(), "{}", path.display());
+        }
+    }
+}